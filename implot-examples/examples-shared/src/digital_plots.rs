@@ -0,0 +1,33 @@
+//! This example demonstrates how digital channel plots are to be used. For more general
+//! features of the libray, see the line_plots example.
+
+use imgui::{CollapsingHeader, Ui};
+use implot::{push_style_var_f32, Plot, PlotDigital, PlotUi, StyleVar};
+
+pub fn show_basic_plot(ui: &Ui, plot_ui: &PlotUi) {
+    ui.text("This header shows digital channels with different sample types.");
+    let content_width = ui.window_content_region_width();
+    Plot::new("Digital plot")
+        // The size call could also be omitted, though the defaults don't consider window
+        // width, which is why we're not doing so here.
+        .size([content_width, 300.0])
+        .build(plot_ui, || {
+            // If this is called outside a plot build callback, the program will panic.
+            let clock_positions = (0..16).map(|x| x as f64).collect::<Vec<_>>();
+            let clock_levels = (0..16).map(|x| (x % 2) as f64).collect::<Vec<_>>();
+            PlotDigital::new("clock").plot(&clock_positions, &clock_levels);
+
+            // Digital channels are sized in pixels, which can be changed through style variables
+            let bit_height = push_style_var_f32(&StyleVar::DigitalBitHeight, 12.0);
+            let gpio_positions = (0..16).map(|x| x as f64).collect::<Vec<_>>();
+            let gpio_levels: Vec<u8> = vec![0, 0, 1, 1, 1, 0, 0, 1, 0, 1, 1, 1, 1, 0, 0, 0];
+            PlotDigital::new("gpio").plot(&gpio_positions, &gpio_levels);
+            bit_height.pop();
+        });
+}
+
+pub fn show_demo_headers(ui: &Ui, plot_ui: &PlotUi) {
+    if CollapsingHeader::new("Digital plots: Basic").build(ui) {
+        show_basic_plot(ui, plot_ui);
+    }
+}
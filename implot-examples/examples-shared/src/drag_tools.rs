@@ -0,0 +1,164 @@
+//! This example demonstrates how the interactive drag tools are to be used. For more general
+//! features of the libray, see the line_plots example.
+
+use imgui::{CollapsingHeader, DragDropFlags, DragDropTarget, Ui};
+use implot::{
+    accept_legend_payload, build_legend_drag_drop_source, DragLine, DragPoint, ImPlotPoint, ImVec4,
+    Plot, PlotLine, PlotOrientation, PlotScatter, PlotUi,
+};
+
+/// State of the drag tools demo.
+pub struct DragToolsDemoState {
+    /// Position of the vertical drag line
+    marker: f64,
+    /// Position of the horizontal drag line
+    threshold: f64,
+    /// Position of the point snapping to a grid
+    grid_point: ImPlotPoint,
+    /// Position of the point snapping to data points
+    peak: ImPlotPoint,
+    /// For each of the series in the legend drag and drop demo, whether it is shown in the
+    /// right plot (as opposed to the left one)
+    series_in_right_plot: [bool; 2],
+}
+
+impl DragToolsDemoState {
+    /// Create a new drag tools demo state object with default values in it.
+    pub fn new() -> Self {
+        Self {
+            marker: 0.3,
+            threshold: 0.5,
+            grid_point: ImPlotPoint { x: 0.2, y: 0.2 },
+            peak: ImPlotPoint { x: 0.7, y: 0.8 },
+            series_in_right_plot: [false, false],
+        }
+    }
+
+    pub fn show_drag_lines_plot(&mut self, ui: &Ui, plot_ui: &PlotUi) {
+        ui.text("This header shows lines that can be dragged with the mouse.");
+        ui.text(format!(
+            "Marker: {:.3}, threshold: {:.3}",
+            self.marker, self.threshold
+        ));
+        let content_width = ui.window_content_region_width();
+        let mut threshold_changed = false;
+        let marker = &mut self.marker;
+        let threshold = &mut self.threshold;
+        Plot::new("Drag lines plot")
+            // The size call could also be omitted, though the defaults don't consider window
+            // width, which is why we're not doing so here.
+            .size([content_width, 300.0])
+            .build(plot_ui, || {
+                let x_positions = vec![0.1, 0.3, 0.5, 0.7, 0.9];
+                let y_positions = vec![0.2, 0.6, 0.4, 0.8, 0.3];
+                PlotLine::new("signal").plot(&x_positions, &y_positions);
+
+                DragLine::new("marker").build(marker);
+                threshold_changed = DragLine::new("threshold")
+                    .with_orientation(PlotOrientation::Horizontal)
+                    .with_color(ImVec4 {
+                        x: 1.0,
+                        y: 0.3,
+                        z: 0.3,
+                        w: 1.0,
+                    })
+                    .with_thickness(2.0)
+                    .build(threshold);
+            });
+        if threshold_changed {
+            ui.text("The threshold was just changed.");
+        }
+    }
+
+    pub fn show_drag_points_plot(&mut self, ui: &Ui, plot_ui: &PlotUi) {
+        ui.text("This header shows points that can be dragged with the mouse.");
+        ui.text(format!(
+            "Picked peak: ({:.3}, {:.3})",
+            self.peak.x, self.peak.y
+        ));
+        let content_width = ui.window_content_region_width();
+        let grid_point = &mut self.grid_point;
+        let peak = &mut self.peak;
+        Plot::new("Drag points plot")
+            // The size call could also be omitted, though the defaults don't consider window
+            // width, which is why we're not doing so here.
+            .size([content_width, 300.0])
+            .build(plot_ui, || {
+                let x_positions = vec![0.1, 0.3, 0.5, 0.7, 0.9];
+                let y_positions = vec![0.2, 0.6, 0.4, 0.8, 0.3];
+                PlotScatter::new("data").plot(&x_positions, &y_positions);
+
+                // This point snaps to a grid and stays within the unit square
+                DragPoint::new("grid point")
+                    .with_grid_snapping(0.1, 0.1)
+                    .with_clamping([0.0, 1.0], [0.0, 1.0])
+                    .build(grid_point);
+
+                // This point can only be placed on the data points
+                DragPoint::new("peak")
+                    .with_radius(6.0)
+                    .with_data_snapping(&x_positions, &y_positions)
+                    .build(peak);
+            });
+    }
+
+    pub fn show_legend_drag_drop_plots(&mut self, ui: &Ui, plot_ui: &PlotUi) {
+        ui.text("Drag legend entries from one plot onto the other to move the series.");
+        let plot_width = ui.window_content_region_width() / 2.0 - 5.0;
+        let series_names = ["sine", "cosine"];
+        let x_positions = (0..50).map(|x| x as f64 * 0.1).collect::<Vec<_>>();
+        let series_values = [
+            x_positions.iter().map(|x| x.sin()).collect::<Vec<_>>(),
+            x_positions.iter().map(|x| x.cos()).collect::<Vec<_>>(),
+        ];
+
+        for (plot_index, plot_title) in ["Left plot", "Right plot"].iter().enumerate() {
+            if plot_index > 0 {
+                ui.same_line();
+            }
+            let in_right_plot = plot_index == 1;
+            Plot::new(plot_title)
+                .size([plot_width, 250.0])
+                .build(plot_ui, || {
+                    for (series_index, name) in series_names.iter().enumerate() {
+                        if self.series_in_right_plot[series_index] != in_right_plot {
+                            continue;
+                        }
+                        PlotLine::new(name).plot(&x_positions, &series_values[series_index]);
+                        build_legend_drag_drop_source(name, DragDropFlags::empty(), |source| {
+                            source.set_payload("DEMO_SERIES", series_index);
+                            ui.text(format!("Moving {}", name));
+                        });
+                    }
+                });
+
+            // The plot is the last item, so it can be used as a drop target directly
+            if let Some(target) = DragDropTarget::new(ui) {
+                if let Some(series_index) =
+                    accept_legend_payload::<usize>(&target, "DEMO_SERIES", DragDropFlags::empty())
+                {
+                    self.series_in_right_plot[series_index] = in_right_plot;
+                }
+                target.pop();
+            }
+        }
+    }
+
+    pub fn show_demo_headers(&mut self, ui: &Ui, plot_ui: &PlotUi) {
+        if CollapsingHeader::new("Drag tools: Drag lines").build(ui) {
+            self.show_drag_lines_plot(ui, plot_ui);
+        }
+        if CollapsingHeader::new("Drag tools: Drag points").build(ui) {
+            self.show_drag_points_plot(ui, plot_ui);
+        }
+        if CollapsingHeader::new("Drag tools: Legend drag and drop").build(ui) {
+            self.show_legend_drag_drop_plots(ui, plot_ui);
+        }
+    }
+}
+
+impl Default for DragToolsDemoState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,55 @@
+//! This example demonstrates how error bars are to be used. For more general
+//! features of the libray, see the line_plots example.
+
+use imgui::{CollapsingHeader, Ui};
+use implot::{Plot, PlotErrorBars, PlotScatter, PlotUi};
+
+pub fn show_basic_vertical_plot(ui: &Ui, plot_ui: &PlotUi) {
+    ui.text("This header shows symmetric vertical error bars around scatter points.");
+    let content_width = ui.window_content_region_width();
+    Plot::new("Vertical error bar plot")
+        // The size call could also be omitted, though the defaults don't consider window
+        // width, which is why we're not doing so here.
+        .size([content_width, 300.0])
+        .build(plot_ui, || {
+            // If this is called outside a plot build callback, the program will panic.
+            let x_positions = vec![0.1, 0.2, 0.3, 0.4, 0.5];
+            let y_positions = vec![0.3, 0.5, 0.4, 0.7, 0.6];
+            let errors = vec![0.05, 0.1, 0.05, 0.15, 0.1];
+            PlotErrorBars::new("measurement").plot(&x_positions, &y_positions, &errors);
+            PlotScatter::new("measurement").plot(&x_positions, &y_positions);
+        });
+}
+
+pub fn show_asymmetric_horizontal_plot(ui: &Ui, plot_ui: &PlotUi) {
+    ui.text("This header shows asymmetric horizontal error bars.");
+    let content_width = ui.window_content_region_width();
+    Plot::new("Horizontal error bar plot")
+        // The size call could also be omitted, though the defaults don't consider window
+        // width, which is why we're not doing so here.
+        .size([content_width, 300.0])
+        .build(plot_ui, || {
+            let x_positions = vec![0.1, 0.2, 0.3, 0.4, 0.5];
+            let y_positions = vec![0.3, 0.5, 0.4, 0.7, 0.6];
+            let negative_errors = vec![0.02, 0.04, 0.02, 0.05, 0.03];
+            let positive_errors = vec![0.05, 0.02, 0.06, 0.01, 0.04];
+            PlotErrorBars::new("measurement")
+                .with_horizontal_bars()
+                .plot_asymmetric(
+                    &x_positions,
+                    &y_positions,
+                    &negative_errors,
+                    &positive_errors,
+                );
+            PlotScatter::new("measurement").plot(&x_positions, &y_positions);
+        });
+}
+
+pub fn show_demo_headers(ui: &Ui, plot_ui: &PlotUi) {
+    if CollapsingHeader::new("Error bars: Symmetric vertical").build(ui) {
+        show_basic_vertical_plot(ui, plot_ui);
+    }
+    if CollapsingHeader::new("Error bars: Asymmetric horizontal").build(ui) {
+        show_asymmetric_horizontal_plot(ui, plot_ui);
+    }
+}
@@ -2,7 +2,10 @@
 //! features of the libray, see the line_plots example.
 
 use imgui::{CollapsingHeader, Ui};
-use implot::{ImPlotPoint, Plot, PlotHeatmap, PlotUi};
+use implot::{
+    push_colormap_from_preset, show_colormap_scale, Colormap, CurrentColormap, ImPlotPoint, Plot,
+    PlotHeatmap, PlotUi,
+};
 
 pub fn show_basic_heatmap(ui: &Ui, plot_ui: &PlotUi) {
     ui.text("This header shows a simple heatmap");
@@ -24,8 +27,35 @@ pub fn show_basic_heatmap(ui: &Ui, plot_ui: &PlotUi) {
         });
 }
 
+pub fn show_heatmap_with_scale(ui: &Ui, plot_ui: &PlotUi) {
+    ui.text("This header shows a heatmap with a colormap scale next to it");
+    // The colormap is only changed for this heatmap and its scale, and restored when the
+    // token goes out of scope.
+    let _colormap = push_colormap_from_preset(Colormap::Viridis);
+    ui.text(format!(
+        "Current colormap: {}",
+        CurrentColormap.name().unwrap_or("custom")
+    ));
+    let scale_width = 100.0;
+    let content_width = ui.window_content_region_width();
+    Plot::new("Heatmap plot with scale")
+        .size([content_width - scale_width, 300.0])
+        .build(plot_ui, || {
+            let values = (0..100).map(|x| 0.1 * x as f64).collect::<Vec<_>>();
+            PlotHeatmap::new("heatmap with scale")
+                .with_scale(0.0, 10.0)
+                .plot(&values, 10, 10);
+        });
+    ui.same_line();
+    // The scale uses the same range as the heatmap above
+    show_colormap_scale(0.0, 10.0, 300.0);
+}
+
 pub fn show_demo_headers(ui: &Ui, plot_ui: &PlotUi) {
     if CollapsingHeader::new("Heatmap: Basic").build(ui) {
         show_basic_heatmap(ui, plot_ui);
     }
+    if CollapsingHeader::new("Heatmap: Colormap scale").build(ui) {
+        show_heatmap_with_scale(ui, plot_ui);
+    }
 }
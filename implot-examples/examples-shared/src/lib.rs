@@ -1,10 +1,16 @@
 pub mod bar_plots;
+pub mod digital_plots;
+pub mod drag_tools;
+pub mod error_bar_plots;
 pub mod heatmaps;
 pub mod line_plots;
+pub mod pie_charts;
 pub mod scatter_plots;
+pub mod shaded_plots;
 pub mod stairs_plots;
 mod stem_plots;
 pub mod text_plots;
+pub mod tools;
 
 use imgui::{Condition, Ui, Window};
 use implot::PlotUi;
@@ -13,6 +19,10 @@ use implot::PlotUi;
 pub struct DemoState {
     /// State of the line plots demo
     line_plots: line_plots::LinePlotDemoState,
+    /// State of the drag tools demo
+    drag_tools: drag_tools::DragToolsDemoState,
+    /// State of the tools demo
+    tools: tools::ToolsDemoState,
 }
 
 impl DemoState {
@@ -20,6 +30,8 @@ impl DemoState {
     pub fn new() -> Self {
         Self {
             line_plots: line_plots::LinePlotDemoState::new(),
+            drag_tools: drag_tools::DragToolsDemoState::new(),
+            tools: tools::ToolsDemoState::new(),
         }
     }
 
@@ -67,6 +79,30 @@ impl DemoState {
                 ui.separator();
                 ui.text("Stem plots:");
                 stem_plots::show_demo_headers(ui, plot_ui);
+
+                ui.separator();
+                ui.text("Shaded plots:");
+                shaded_plots::show_demo_headers(ui, plot_ui);
+
+                ui.separator();
+                ui.text("Error bars:");
+                error_bar_plots::show_demo_headers(ui, plot_ui);
+
+                ui.separator();
+                ui.text("Pie charts:");
+                pie_charts::show_demo_headers(ui, plot_ui);
+
+                ui.separator();
+                ui.text("Digital plots:");
+                digital_plots::show_demo_headers(ui, plot_ui);
+
+                ui.separator();
+                ui.text("Drag tools:");
+                self.drag_tools.show_demo_headers(ui, plot_ui);
+
+                ui.separator();
+                ui.text("Tools:");
+                self.tools.show_demo_headers(ui, plot_ui);
             });
     }
 }
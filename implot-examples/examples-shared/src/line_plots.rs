@@ -1,14 +1,14 @@
 //! This example demonstrates how line plots are to be used, along with some querying features
 //! that will be applicable to all kinds of plots.
 
-use imgui::{CollapsingHeader, Condition, Ui};
+use imgui::{CollapsingHeader, Condition, MouseButton, Slider, Ui};
 use implot::{
-    get_plot_limits, get_plot_mouse_position, get_plot_query, is_legend_entry_hovered,
-    is_plot_hovered, is_plot_queried, pixels_to_plot_vec2, plot_to_pixels_vec2, push_style_color,
-    push_style_var_f32, push_style_var_i32, set_colormap_from_preset, set_colormap_from_vec,
-    set_plot_y_axis, AxisFlags, Colormap, ImPlotLimits, ImPlotPoint, ImPlotRange, ImVec2, ImVec4,
-    Marker, Plot, PlotColorElement, PlotFlags, PlotLine, PlotLocation, PlotOrientation, PlotUi,
-    StyleVar, YAxisChoice,
+    build_legend_popup, get_plot_limits, get_plot_mouse_position, get_plot_query,
+    is_legend_entry_hovered, is_plot_hovered, is_plot_queried, pixels_to_plot_vec2,
+    plot_to_pixels_vec2, push_style_color, push_style_var_f32, push_style_var_i32,
+    set_colormap_from_preset, set_colormap_from_vec, set_plot_y_axis, AxisFlags, Colormap,
+    ImPlotLimits, ImPlotPoint, ImPlotRange, ImVec2, ImVec4, Marker, Plot, PlotColorElement,
+    PlotFlags, PlotLine, PlotLocation, PlotOrientation, PlotUi, Strided, StyleVar, YAxisChoice,
 };
 
 use std::{cell::RefCell, rc::Rc};
@@ -16,6 +16,8 @@ use std::{cell::RefCell, rc::Rc};
 /// State of the line plots demo.
 pub struct LinePlotDemoState {
     linked_limits: Rc<RefCell<ImPlotRange>>,
+    /// Vertical offset of the line in the legend popup demo, changed through the popup
+    legend_popup_offset: f32,
 }
 
 impl LinePlotDemoState {
@@ -23,6 +25,7 @@ impl LinePlotDemoState {
     pub fn new() -> Self {
         Self {
             linked_limits: Rc::new(RefCell::new(ImPlotRange { Min: 0.0, Max: 1.0 })),
+            legend_popup_offset: 0.0,
         }
     }
 
@@ -41,6 +44,113 @@ impl LinePlotDemoState {
             });
     }
 
+    pub fn show_integer_data_plot(ui: &Ui, plot_ui: &PlotUi) {
+        ui.text("This header shows that data can also be plotted from other numeric types.");
+        let content_width = ui.window_content_region_width();
+        Plot::new("Integer data line plot")
+            // The size call could also be omitted, though the defaults don't consider window
+            // width, which is why we're not doing so here.
+            .size([content_width, 300.0])
+            .build(plot_ui, || {
+                // For example raw ADC samples, which are plotted without converting them first
+                let sample_indices: Vec<i16> = (0..100).collect();
+                let samples: Vec<i16> = sample_indices.iter().map(|i| (i % 20) * 50).collect();
+                PlotLine::new("i16 samples").plot(&sample_indices, &samples);
+
+                let x_positions: Vec<f32> = vec![0.0, 50.0, 100.0];
+                let y_positions: Vec<f32> = vec![100.0, 900.0, 100.0];
+                PlotLine::new("f32 values").plot(&x_positions, &y_positions);
+            });
+    }
+
+    pub fn show_strided_data_plot(ui: &Ui, plot_ui: &PlotUi) {
+        ui.text("This header shows how to plot data from structs and interleaved buffers.");
+        let content_width = ui.window_content_region_width();
+        Plot::new("Strided data line plot")
+            // The size call could also be omitted, though the defaults don't consider window
+            // width, which is why we're not doing so here.
+            .size([content_width, 300.0])
+            .build(plot_ui, || {
+                // Samples stored as structs can be plotted by picking out their fields
+                struct Sample {
+                    time: f64,
+                    value: f64,
+                    _flags: u32,
+                }
+                let samples: Vec<Sample> = (0..10)
+                    .map(|i| Sample {
+                        time: 0.1 * i as f64,
+                        value: 0.5 + 0.05 * i as f64,
+                        _flags: 0,
+                    })
+                    .collect();
+                PlotLine::new("struct fields").plot_strided(
+                    Strided::from_records(&samples, |sample| &sample.time),
+                    Strided::from_records(&samples, |sample| &sample.value),
+                );
+
+                // Interleaved buffers hold x and y values in turns
+                let interleaved = vec![0.0, 0.1, 0.5, 0.3, 0.9, 0.2];
+                PlotLine::new("interleaved").plot_strided(
+                    Strided::interleaved(&interleaved, 2, 0),
+                    Strided::interleaved(&interleaved, 2, 1),
+                );
+
+                // For ring buffers, the offset is the index of the oldest value, which is
+                // then drawn first
+                let ring_x = vec![0.7, 0.8, 0.9, 0.4, 0.5, 0.6];
+                let ring_y = vec![0.9, 0.8, 0.9, 0.8, 0.9, 0.8];
+                let oldest = 3;
+                PlotLine::new("ring buffer").plot_strided(
+                    Strided::new(&ring_x).with_offset(oldest),
+                    Strided::new(&ring_y).with_offset(oldest),
+                );
+            });
+    }
+
+    pub fn show_computed_data_plot(ui: &Ui, plot_ui: &PlotUi) {
+        ui.text("This header shows how to plot data computed on the fly by a closure.");
+        let content_width = ui.window_content_region_width();
+        Plot::new("Computed data line plot")
+            // The size call could also be omitted, though the defaults don't consider window
+            // width, which is why we're not doing so here.
+            .size([content_width, 300.0])
+            .build(plot_ui, || {
+                // The closure is asked for the point at each index, so no vectors are needed
+                let frequency = 2.0;
+                PlotLine::new("sine").plot_fn(200, |i| {
+                    let x = i as f64 / 199.0;
+                    ImPlotPoint {
+                        x,
+                        y: 0.5 + 0.4 * (2.0 * std::f64::consts::PI * frequency * x).sin(),
+                    }
+                });
+
+                // This also works for transforming existing data without copying it
+                let raw = [3, 5, 4, 8, 6, 9];
+                PlotLine::new("normalized").plot_fn(raw.len(), |i| ImPlotPoint {
+                    x: i as f64 / 5.0,
+                    y: raw[i] as f64 / 10.0,
+                });
+            });
+    }
+
+    pub fn show_y_values_plot(ui: &Ui, plot_ui: &PlotUi) {
+        ui.text("This header shows how to plot y values with evenly spaced x positions.");
+        let content_width = ui.window_content_region_width();
+        Plot::new("Y values line plot")
+            // The size call could also be omitted, though the defaults don't consider window
+            // width, which is why we're not doing so here.
+            .size([content_width, 300.0])
+            .build(plot_ui, || {
+                let samples = vec![0.2, 0.4, 0.3, 0.5, 0.45, 0.6];
+                // The x positions are the sample indices here...
+                PlotLine::new("indices").plot_y(&samples);
+                // ...and start at 1.0 with a distance of 0.5 here, like a sampled signal
+                PlotLine::new("scaled").plot_y_scaled(&samples, 1.0, 0.5);
+            });
+    }
+
     pub fn show_two_yaxis_plot(ui: &Ui, plot_ui: &PlotUi) {
         ui.text("This header shows how to create a plot with multiple Y axes.");
         let content_width = ui.window_content_region_width();
@@ -90,6 +200,71 @@ impl LinePlotDemoState {
             });
     }
 
+    pub fn show_legend_popup_plot(&mut self, ui: &Ui, plot_ui: &PlotUi) {
+        ui.text("Right-click the legend entry of this plot to change the line offset.");
+        let content_width = ui.window_content_region_width();
+        let offset = &mut self.legend_popup_offset;
+        Plot::new("Legend popup line plot")
+            // The size call could also be omitted, though the defaults don't consider window
+            // width, which is why we're not doing so here.
+            .size([content_width, 300.0])
+            .build(plot_ui, || {
+                let x_positions = vec![0.1, 0.9];
+                let y_positions = vec![0.1 + *offset as f64, 0.9 + *offset as f64];
+                PlotLine::new("offset line").plot(&x_positions, &y_positions);
+
+                // The popup contents are regular imgui widgets
+                build_legend_popup("offset line", MouseButton::Right, || {
+                    ui.text("Line settings");
+                    Slider::new("offset", -0.5, 0.5).build(ui, offset);
+                });
+            });
+    }
+
+    pub fn show_item_style_plot(ui: &Ui, plot_ui: &PlotUi) {
+        ui.text("This header shows how to style individual lines without push/pop.");
+        let content_width = ui.window_content_region_width();
+        let mut default_line_color = None;
+        Plot::new("Item style line plot")
+            // The size call could also be omitted, though the defaults don't consider window
+            // width, which is why we're not doing so here.
+            .size([content_width, 300.0])
+            .build(plot_ui, || {
+                let x_positions = vec![0.1, 0.3, 0.5, 0.7, 0.9];
+                let y_positions = vec![0.2, 0.6, 0.4, 0.8, 0.3];
+                PlotLine::new("styled line")
+                    .with_line_color(ImVec4 {
+                        x: 1.0,
+                        y: 0.5,
+                        z: 0.0,
+                        w: 1.0,
+                    })
+                    .with_line_weight(3.0)
+                    .with_marker(Marker::Diamond, 6.0)
+                    .plot(&x_positions, &y_positions);
+
+                // The styles above only affect the line they were set on
+                let y_positions = y_positions.iter().map(|y| y - 0.1).collect::<Vec<_>>();
+                // The color that was picked automatically is returned from the plot call
+                default_line_color = PlotLine::new("default line")
+                    .plot(&x_positions, &y_positions)
+                    .color();
+
+                // This line starts out hidden, but can be shown by clicking its legend entry
+                let y_positions = y_positions.iter().map(|y| y - 0.1).collect::<Vec<_>>();
+                PlotLine::new("initially hidden line")
+                    .hidden(true, Condition::Once)
+                    .plot(&x_positions, &y_positions);
+            });
+
+        if let Some(color) = default_line_color {
+            ui.text_colored(
+                [color.x, color.y, color.z, color.w],
+                "This text has the color of the default line.",
+            );
+        }
+    }
+
     pub fn show_configurable_plot(ui: &Ui, plot_ui: &PlotUi) {
         ui.text("This header demos what we can configure about plots.");
 
@@ -371,10 +546,103 @@ impl LinePlotDemoState {
             });
     }
 
+    pub fn show_plot_rect_plot(ui: &Ui, plot_ui: &PlotUi) {
+        ui.text("This header shows how to place imgui widgets on top of a plot area.");
+        let content_width = ui.window_content_region_width();
+        // To read the plot area's position and size, the token returned by begin() is needed,
+        // so build() can't be used here.
+        if let Some(token) = Plot::new("Plot rect line plot")
+            // The size call could also be omitted, though the defaults don't consider window
+            // width, which is why we're not doing so here.
+            .size([content_width, 300.0])
+            .begin(plot_ui)
+        {
+            let x_positions = vec![0.1, 0.9];
+            let y_positions = vec![0.1, 0.9];
+            PlotLine::new("legend label").plot(&x_positions, &y_positions);
+
+            let (top_left, bottom_right) = token.plot_rect();
+            token.end();
+
+            // Put some text into the bottom right corner of the plot area, then go back to
+            // where the cursor was so the layout continues below the plot.
+            let cursor_position = ui.cursor_screen_pos();
+            let text = "overlaid text";
+            let text_size = ui.calc_text_size(text);
+            ui.set_cursor_screen_pos([
+                bottom_right.x - text_size[0] - 5.0,
+                bottom_right.y - text_size[1] - 5.0,
+            ]);
+            ui.text(text);
+            ui.set_cursor_screen_pos(cursor_position);
+            ui.text(format!(
+                "Plot area: ({:.0}, {:.0}) to ({:.0}, {:.0})",
+                top_left.x, top_left.y, bottom_right.x, bottom_right.y
+            ));
+        }
+    }
+
+    pub fn show_custom_drawing_plot(ui: &Ui, plot_ui: &PlotUi) {
+        ui.text("This header shows how to draw custom shapes in plot coordinates.");
+        let content_width = ui.window_content_region_width();
+        // The draw list is obtained from the token returned by begin(), so build() can't be
+        // used here.
+        if let Some(token) = Plot::new("Custom drawing line plot")
+            // The size call could also be omitted, though the defaults don't consider window
+            // width, which is why we're not doing so here.
+            .size([content_width, 300.0])
+            .begin(plot_ui)
+        {
+            let x_positions = vec![0.1, 0.9];
+            let y_positions = vec![0.1, 0.9];
+            PlotLine::new("legend label").plot(&x_positions, &y_positions);
+
+            // The shapes follow the plot when panning and zooming, and are clipped to the
+            // plot area. The draw list has to be dropped before the plot is ended.
+            {
+                let draw_list = token.draw_list();
+                let orange = ImVec4 {
+                    x: 1.0,
+                    y: 0.5,
+                    z: 0.0,
+                    w: 1.0,
+                };
+                draw_list.add_rect(
+                    ImPlotPoint { x: 0.2, y: 0.6 },
+                    ImPlotPoint { x: 0.4, y: 0.8 },
+                    orange,
+                    2.0,
+                );
+                draw_list.add_triangle_filled(
+                    ImPlotPoint { x: 0.6, y: 0.2 },
+                    ImPlotPoint { x: 0.8, y: 0.2 },
+                    ImPlotPoint { x: 0.7, y: 0.4 },
+                    orange,
+                );
+                draw_list.add_circle(ImPlotPoint { x: 0.5, y: 0.5 }, 10.0, orange, 2.0);
+                draw_list.add_text(ImPlotPoint { x: 0.5, y: 0.5 }, orange, "custom glyph");
+            }
+
+            token.end();
+        }
+    }
+
     pub fn show_demo_headers(&mut self, ui: &Ui, plot_ui: &PlotUi) {
         if CollapsingHeader::new("Line plot: Basic").build(ui) {
             Self::show_basic_plot(ui, plot_ui);
         }
+        if CollapsingHeader::new("Line plot: Integer data").build(ui) {
+            Self::show_integer_data_plot(ui, plot_ui);
+        }
+        if CollapsingHeader::new("Line plot: Strided data").build(ui) {
+            Self::show_strided_data_plot(ui, plot_ui);
+        }
+        if CollapsingHeader::new("Line plot: Computed data").build(ui) {
+            Self::show_computed_data_plot(ui, plot_ui);
+        }
+        if CollapsingHeader::new("Line plot: Y values only").build(ui) {
+            Self::show_y_values_plot(ui, plot_ui);
+        }
         if CollapsingHeader::new("Line plot: Configured").build(ui) {
             Self::show_configurable_plot(ui, plot_ui);
         }
@@ -384,6 +652,9 @@ impl LinePlotDemoState {
         if CollapsingHeader::new("Line plot: Plot styling").build(ui) {
             Self::show_style_plot(ui, plot_ui);
         }
+        if CollapsingHeader::new("Line plot: Per-item styling").build(ui) {
+            Self::show_item_style_plot(ui, plot_ui);
+        }
         if CollapsingHeader::new("Line plot: Colormaps").build(ui) {
             Self::show_colormaps_plot(ui, plot_ui);
         }
@@ -399,6 +670,15 @@ impl LinePlotDemoState {
         if CollapsingHeader::new("Line plot: Linked plots").build(ui) {
             self.show_linked_x_axis_plots(ui, plot_ui);
         }
+        if CollapsingHeader::new("Line plot: Legend popups").build(ui) {
+            self.show_legend_popup_plot(ui, plot_ui);
+        }
+        if CollapsingHeader::new("Line plot: Custom drawing").build(ui) {
+            Self::show_custom_drawing_plot(ui, plot_ui);
+        }
+        if CollapsingHeader::new("Line plot: Overlaid widgets").build(ui) {
+            Self::show_plot_rect_plot(ui, plot_ui);
+        }
     }
 }
 
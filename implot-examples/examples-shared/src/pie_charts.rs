@@ -0,0 +1,32 @@
+//! This example demonstrates how pie charts are to be used. For more general
+//! features of the libray, see the line_plots example.
+
+use imgui::{CollapsingHeader, Condition, Ui};
+use implot::{ImPlotPoint, Plot, PlotFlags, PlotPieChart, PlotUi};
+
+pub fn show_basic_pie_chart(ui: &Ui, plot_ui: &PlotUi) {
+    ui.text("This header shows a simple pie chart.");
+    let content_width = ui.window_content_region_width();
+    Plot::new("Pie chart")
+        // The size call could also be omitted, though the defaults don't consider window
+        // width, which is why we're not doing so here.
+        .size([content_width, 300.0])
+        .x_limits([0.0, 1.0], Condition::Always)
+        .y1_limits([0.0, 1.0], Condition::Always)
+        .with_plot_flags(&(PlotFlags::AXIS_EQUAL | PlotFlags::ANTIALIASED))
+        .build(plot_ui, || {
+            // If this is called outside a plot build callback, the program will panic.
+            let values = vec![0.15, 0.30, 0.25, 0.2];
+            PlotPieChart::new(&["Frogs", "Hogs", "Dogs", "Logs"])
+                .with_center(ImPlotPoint { x: 0.5, y: 0.5 })
+                .with_radius(0.4)
+                .with_value_labels(Some(2))
+                .plot(&values);
+        });
+}
+
+pub fn show_demo_headers(ui: &Ui, plot_ui: &PlotUi) {
+    if CollapsingHeader::new("Pie chart: Basic").build(ui) {
+        show_basic_pie_chart(ui, plot_ui);
+    }
+}
@@ -0,0 +1,48 @@
+//! This example demonstrates how shaded plots are to be used. For more general
+//! features of the libray, see the line_plots example.
+
+use imgui::{CollapsingHeader, Ui};
+use implot::{Plot, PlotLine, PlotShaded, PlotUi};
+
+pub fn show_basic_plot(ui: &Ui, plot_ui: &PlotUi) {
+    ui.text("This header shows an area filled towards a reference value.");
+    let content_width = ui.window_content_region_width();
+    Plot::new("Shaded plot")
+        // The size call could also be omitted, though the defaults don't consider window
+        // width, which is why we're not doing so here.
+        .size([content_width, 300.0])
+        .build(plot_ui, || {
+            // If this is called outside a plot build callback, the program will panic.
+            let x_positions = vec![0.1, 0.2, 0.3, 0.4, 0.5, 0.6];
+            let y_positions = vec![0.3, 0.5, 0.4, 0.7, 0.6, 0.8];
+            PlotShaded::new("legend label")
+                .with_reference_y(0.2)
+                .plot(&x_positions, &y_positions);
+        });
+}
+
+pub fn show_band_plot(ui: &Ui, plot_ui: &PlotUi) {
+    ui.text("This header shows a line with a shaded band around it.");
+    let content_width = ui.window_content_region_width();
+    Plot::new("Shaded band plot")
+        // The size call could also be omitted, though the defaults don't consider window
+        // width, which is why we're not doing so here.
+        .size([content_width, 300.0])
+        .build(plot_ui, || {
+            let x_positions = vec![0.1, 0.2, 0.3, 0.4, 0.5, 0.6];
+            let y_positions = vec![0.3, 0.5, 0.4, 0.7, 0.6, 0.8];
+            let lower = y_positions.iter().map(|y| y - 0.1).collect::<Vec<_>>();
+            let upper = y_positions.iter().map(|y| y + 0.1).collect::<Vec<_>>();
+            PlotShaded::new("band").plot_between(&x_positions, &lower, &upper);
+            PlotLine::new("line").plot(&x_positions, &y_positions);
+        });
+}
+
+pub fn show_demo_headers(ui: &Ui, plot_ui: &PlotUi) {
+    if CollapsingHeader::new("Shaded plots: Reference value").build(ui) {
+        show_basic_plot(ui, plot_ui);
+    }
+    if CollapsingHeader::new("Shaded plots: Band between lines").build(ui) {
+        show_band_plot(ui, plot_ui);
+    }
+}
@@ -2,7 +2,7 @@
 //! features of the libray, see the line_plots example.
 
 use imgui::{CollapsingHeader, Ui};
-use implot::{Plot, PlotText, PlotUi};
+use implot::{Annotation, ImPlotPoint, ImVec4, Plot, PlotText, PlotUi};
 
 pub fn show_basic_plot(ui: &Ui, plot_ui: &PlotUi) {
     ui.text("This header just plots some text with as little code as possible.");
@@ -26,8 +26,44 @@ pub fn show_basic_plot(ui: &Ui, plot_ui: &PlotUi) {
         });
 }
 
+pub fn show_annotations_plot(ui: &Ui, plot_ui: &PlotUi) {
+    ui.text("This header shows annotations, which are labels attached to points.");
+    let content_width = ui.window_content_region_width();
+    Plot::new("Annotations plot")
+        // The size call could also be omitted, though the defaults don't consider window
+        // width, which is why we're not doing so here.
+        .size([content_width, 300.0])
+        .build(plot_ui, || {
+            Annotation::new()
+                .with_pixel_offset(10.0, -10.0)
+                .plot(ImPlotPoint { x: 0.25, y: 0.25 }, "plain annotation");
+
+            // Formatting happens in Rust, so any text (including % signs) can be shown.
+            let share = 0.42;
+            Annotation::new()
+                .with_color(ImVec4 {
+                    x: 0.8,
+                    y: 0.3,
+                    z: 0.3,
+                    w: 1.0,
+                })
+                .plot_fmt(
+                    ImPlotPoint { x: 0.75, y: 0.5 },
+                    format_args!("{:.0}% done", share * 100.0),
+                );
+
+            // Clamped annotations stay within the plot area.
+            Annotation::new()
+                .clamped()
+                .plot(ImPlotPoint { x: 1.0, y: 1.0 }, "clamped to the corner");
+        });
+}
+
 pub fn show_demo_headers(ui: &Ui, plot_ui: &PlotUi) {
     if CollapsingHeader::new("Text plot: Basic").build(ui) {
         show_basic_plot(ui, plot_ui);
     }
+    if CollapsingHeader::new("Text plot: Annotations").build(ui) {
+        show_annotations_plot(ui, plot_ui);
+    }
 }
@@ -0,0 +1,63 @@
+//! This example demonstrates the tool windows and widgets ImPlot provides, such as the style
+//! editor. For more general features of the libray, see the line_plots example.
+
+use imgui::{CollapsingHeader, Ui};
+use implot::{
+    show_colormap_selector, show_metrics_window, show_style_editor, show_style_selector,
+    show_user_guide, PlotUi,
+};
+
+/// State of the tools demo.
+pub struct ToolsDemoState {
+    /// Whether the metrics window is shown
+    show_metrics: bool,
+}
+
+impl ToolsDemoState {
+    /// Create a new tools demo state object with default values in it.
+    pub fn new() -> Self {
+        Self {
+            show_metrics: false,
+        }
+    }
+
+    pub fn show_style_editor_demo(ui: &Ui) {
+        ui.text("This header shows the style editor, which edits the style of all plots.");
+        show_style_editor(None);
+    }
+
+    pub fn show_selectors(ui: &Ui) {
+        ui.text("These combo boxes switch the color style and the colormap of all plots.");
+        show_style_selector("Plot style");
+        show_colormap_selector("Colormap");
+    }
+
+    pub fn show_metrics(&mut self, ui: &Ui) {
+        ui.checkbox("Show metrics window", &mut self.show_metrics);
+        if self.show_metrics {
+            // Closing the window resets the flag, which also unticks the checkbox
+            show_metrics_window(&mut self.show_metrics);
+        }
+    }
+
+    pub fn show_demo_headers(&mut self, ui: &Ui, _plot_ui: &PlotUi) {
+        if CollapsingHeader::new("Tools: Style editor").build(ui) {
+            Self::show_style_editor_demo(ui);
+        }
+        if CollapsingHeader::new("Tools: Style and colormap selectors").build(ui) {
+            Self::show_selectors(ui);
+        }
+        if CollapsingHeader::new("Tools: Metrics").build(ui) {
+            self.show_metrics(ui);
+        }
+        if CollapsingHeader::new("Tools: User guide").build(ui) {
+            show_user_guide();
+        }
+    }
+}
+
+impl Default for ToolsDemoState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
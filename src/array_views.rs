@@ -0,0 +1,146 @@
+//! # Array views module
+//!
+//! This module lets plot elements take their data from [`ndarray`] arrays, through the
+//! `plot_ndarray` functions on [`PlotLine`], [`PlotScatter`] and [`PlotHeatmap`]. Data is read
+//! in place where ImPlot supports the memory layout, and copied otherwise. It is only available
+//! with the `ndarray` feature enabled.
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use ndarray::{ArrayView1, ArrayView2};
+
+use crate::{PlotData, PlotHeatmap, PlotItemResult, PlotLine, PlotScatter, Strided};
+
+/// Error returned by the `plot_ndarray` functions when the x and y arrays have different
+/// lengths. Nothing is plotted in that case, as opposed to the slice-based functions, which plot
+/// as many points as both slices have.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LengthMismatchError {
+    /// Length of the x array
+    x_len: usize,
+    /// Length of the y array
+    y_len: usize,
+}
+
+impl LengthMismatchError {
+    /// Length of the x array that was passed.
+    pub fn x_len(&self) -> usize {
+        self.x_len
+    }
+
+    /// Length of the y array that was passed.
+    pub fn y_len(&self) -> usize {
+        self.y_len
+    }
+}
+
+impl fmt::Display for LengthMismatchError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "x and y arrays have different lengths ({} and {})",
+            self.x_len, self.y_len
+        )
+    }
+}
+
+impl std::error::Error for LengthMismatchError {}
+
+/// Call `plot` with strided views of the two arrays if ImPlot can read them in place, which is
+/// the case if they have the same stride and it is not negative, and with copies of them
+/// otherwise.
+fn plot_array_pair<T, F>(
+    x: ArrayView1<T>,
+    y: ArrayView1<T>,
+    plot: F,
+) -> Result<PlotItemResult, LengthMismatchError>
+where
+    T: PlotData,
+    F: Fn(Strided<T>, Strided<T>) -> PlotItemResult,
+{
+    if x.len() != y.len() {
+        return Err(LengthMismatchError {
+            x_len: x.len(),
+            y_len: y.len(),
+        });
+    }
+
+    let x_stride = x.strides()[0];
+    let y_stride = y.strides()[0];
+    if x_stride == y_stride && x_stride >= 0 {
+        let stride = x_stride as usize * std::mem::size_of::<T>();
+        // Safe because the views borrow the arrays for as long as the strided views exist, and
+        // the pointers, lengths and strides describe exactly the elements of the arrays.
+        let (x, y) = unsafe {
+            (
+                Strided::from_raw_parts(x.as_ptr(), x.len(), stride),
+                Strided::from_raw_parts(y.as_ptr(), y.len(), stride),
+            )
+        };
+        Ok(plot(x, y))
+    } else {
+        // ImPlot takes one stride for both x and y values, so arrays with different layouts
+        // have to be brought into the same one first.
+        let x = x.to_vec();
+        let y = y.to_vec();
+        Ok(plot(Strided::new(&x), Strided::new(&y)))
+    }
+}
+
+impl PlotLine {
+    /// Like `plot`, but with the values taken from `ndarray` arrays, which are read in place
+    /// if they share the same stride. Use this in closures passed to
+    /// [`Plot::build()`](struct.Plot.html#method.build)
+    ///
+    /// Returns an error if the arrays have different lengths.
+    pub fn plot_ndarray<T: PlotData>(
+        &self,
+        x: ArrayView1<T>,
+        y: ArrayView1<T>,
+    ) -> Result<PlotItemResult, LengthMismatchError> {
+        plot_array_pair(x, y, |x, y| self.plot_strided(x, y))
+    }
+}
+
+impl PlotScatter {
+    /// Like `plot`, but with the values taken from `ndarray` arrays, which are read in place
+    /// if they share the same stride. Use this in closures passed to
+    /// [`Plot::build()`](struct.Plot.html#method.build)
+    ///
+    /// Returns an error if the arrays have different lengths.
+    pub fn plot_ndarray<T: PlotData>(
+        &self,
+        x: ArrayView1<T>,
+        y: ArrayView1<T>,
+    ) -> Result<PlotItemResult, LengthMismatchError> {
+        plot_array_pair(x, y, |x, y| self.plot_strided(x, y))
+    }
+}
+
+impl PlotHeatmap {
+    /// Like `plot`, but with the values and the number of rows and columns taken from an
+    /// `ndarray` array. Arrays in row-major (standard) layout are read in place, others, such as
+    /// column-major arrays, are copied into row-major order first. Use this in closures passed
+    /// to [`Plot::build()`](struct.Plot.html#method.build)
+    ///
+    /// # Panics
+    /// Will panic if the array has more than `u32::MAX` rows or columns.
+    pub fn plot_ndarray<T: PlotData>(&self, values: ArrayView2<T>) -> PlotItemResult {
+        // If there is no data to plot, we stop here
+        if values.is_empty() {
+            return PlotItemResult::not_plotted();
+        }
+        let (rows, cols) = values.dim();
+        let rows = u32::try_from(rows).expect("Heatmap has too many rows");
+        let cols = u32::try_from(cols).expect("Heatmap has too many columns");
+        match values.as_slice() {
+            Some(values) => self.plot(values, rows, cols),
+            None => {
+                // Iteration is in logical order, which is row-major
+                let values: Vec<T> = values.iter().copied().collect();
+                self.plot(&values, rows, cols)
+            }
+        }
+    }
+}
@@ -5,7 +5,7 @@
 use parking_lot::ReentrantMutex;
 
 use crate::sys;
-use crate::PlotUi;
+use crate::{PlotStyle, PlotUi};
 /// An implot context.
 ///
 /// A context is required to do most of the things this library provides. While this was created
@@ -37,6 +37,9 @@ impl Context {
         );
 
         let ctx = unsafe { sys::ImPlot_CreateContext() };
+        // New contexts start out with the standard colormap and nothing pushed
+        crate::set_current_colormap_preset(Some(crate::Colormap::Standard));
+        crate::clear_colormap_stack();
         unsafe {
             sys::ImPlot_SetCurrentContext(ctx);
         }
@@ -49,10 +52,23 @@ impl Context {
         PlotUi { context: self }
     }
 
-    /// Use light colors for the implot style.
-    ///
-    /// This will eventually be exposed more thoroughly in the form of ImPlotStyle,
-    /// but for now this allows one to at least easily set the color preset.
+    /// Get a copy of the style of this context. Changes to it only take effect once it is
+    /// passed to [`Context::set_style`].
+    #[rustversion::attr(since(1.48), doc(alias = "GetStyle"))]
+    pub fn style(&self) -> PlotStyle {
+        unsafe { *PlotStyle::from_raw(sys::ImPlot_GetStyle()) }
+    }
+
+    /// Set the style of this context, for changing style settings persistently.
+    #[rustversion::attr(since(1.48), doc(alias = "GetStyle"))]
+    pub fn set_style(&mut self, style: &PlotStyle) {
+        unsafe {
+            *PlotStyle::from_raw_mut(sys::ImPlot_GetStyle()) = *style;
+        }
+    }
+
+    /// Use light colors for the implot style. Individual colors can be changed afterwards
+    /// through [`Context::set_style`].
     pub fn use_light_colors(&self) {
         unsafe {
             let style = sys::ImPlot_GetStyle();
@@ -61,10 +77,8 @@ impl Context {
         }
     }
 
-    /// Use dark colors for the implot style.
-    ///
-    /// This will eventually be exposed more thoroughly in the form of ImPlotStyle,
-    /// but for now this allows one to at least easily set the color preset.
+    /// Use dark colors for the implot style. Individual colors can be changed afterwards
+    /// through [`Context::set_style`].
     pub fn use_dark_colors(&self) {
         unsafe {
             let style = sys::ImPlot_GetStyle();
@@ -73,10 +87,8 @@ impl Context {
         }
     }
 
-    /// Use classic colors for the implot style.
-    ///
-    /// This will eventually be exposed more thoroughly in the form of ImPlotStyle,
-    /// but for now this allows one to at least easily set the color preset.
+    /// Use classic colors for the implot style. Individual colors can be changed afterwards
+    /// through [`Context::set_style`].
     pub fn use_classic_colors(&self) {
         unsafe {
             let style = sys::ImPlot_GetStyle();
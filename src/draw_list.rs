@@ -0,0 +1,223 @@
+//! # Draw list module
+//!
+//! This module defines [`PlotDrawList`], which allows drawing custom shapes into the plot area
+//! using plot coordinates. It is obtained through [`PlotToken::draw_list`].
+
+use std::marker::PhantomData;
+use std::os::raw::c_char;
+
+use crate::{plot_to_pixels_vec2, sys, ImPlotPoint, ImVec2, ImVec4, PlotToken};
+
+/// Draw list of the current plot, clipped to the plot area. All positions passed to the drawing
+/// functions are in plot coordinates and converted to pixels with the currently selected Y axis,
+/// so the shapes follow panning and zooming. Sizes such as thicknesses and radii are in pixels.
+/// The clip rect is removed again when this is dropped.
+#[rustversion::attr(since(1.48), doc(alias = "GetPlotDrawList"))]
+pub struct PlotDrawList<'a> {
+    raw: *mut sys::ImDrawList,
+    /// The draw list must not outlive the plot it draws into
+    token: PhantomData<&'a PlotToken>,
+}
+
+impl<'a> PlotDrawList<'a> {
+    /// Get the draw list of the current plot and set up clipping to the plot area.
+    pub(crate) fn new(_token: &'a PlotToken) -> Self {
+        let raw = unsafe {
+            sys::ImPlot_PushPlotClipRect();
+            sys::ImPlot_GetPlotDrawList()
+        };
+        assert_ne!(raw, std::ptr::null_mut());
+        Self {
+            raw,
+            token: PhantomData,
+        }
+    }
+
+    /// Draw a line between two points.
+    pub fn add_line(&self, p1: ImPlotPoint, p2: ImPlotPoint, color: ImVec4, thickness: f32) {
+        unsafe {
+            imgui::sys::ImDrawList_AddLine(
+                self.raw,
+                to_pixels(p1),
+                to_pixels(p2),
+                color_to_u32(color),
+                thickness,
+            );
+        }
+    }
+
+    /// Draw a line through the given points, optionally closing it back to the first point.
+    pub fn add_polyline(
+        &self,
+        points: &[ImPlotPoint],
+        color: ImVec4,
+        closed: bool,
+        thickness: f32,
+    ) {
+        let pixel_points: Vec<ImVec2> = points.iter().map(|&point| to_pixels(point)).collect();
+        unsafe {
+            // "as" casts saturate as of Rust 1.45. This is safe here.
+            imgui::sys::ImDrawList_AddPolyline(
+                self.raw,
+                pixel_points.as_ptr(),
+                pixel_points.len() as i32,
+                color_to_u32(color),
+                if closed {
+                    imgui::sys::ImDrawFlags_Closed as i32
+                } else {
+                    0
+                },
+                thickness,
+            );
+        }
+    }
+
+    /// Draw the outline of the axis-aligned rectangle spanned by two corners.
+    pub fn add_rect(&self, p1: ImPlotPoint, p2: ImPlotPoint, color: ImVec4, thickness: f32) {
+        let (min, max) = pixel_rect(p1, p2);
+        unsafe {
+            imgui::sys::ImDrawList_AddRect(
+                self.raw,
+                min,
+                max,
+                color_to_u32(color),
+                0.0, // No rounding
+                0,   // No flags
+                thickness,
+            );
+        }
+    }
+
+    /// Draw a filled axis-aligned rectangle spanned by two corners.
+    pub fn add_rect_filled(&self, p1: ImPlotPoint, p2: ImPlotPoint, color: ImVec4) {
+        let (min, max) = pixel_rect(p1, p2);
+        unsafe {
+            imgui::sys::ImDrawList_AddRectFilled(
+                self.raw,
+                min,
+                max,
+                color_to_u32(color),
+                0.0, // No rounding
+                0,   // No flags
+            );
+        }
+    }
+
+    /// Draw the outline of a circle. The radius is given in pixels, so the circle keeps its
+    /// size when zooming.
+    pub fn add_circle(&self, center: ImPlotPoint, radius: f32, color: ImVec4, thickness: f32) {
+        unsafe {
+            imgui::sys::ImDrawList_AddCircle(
+                self.raw,
+                to_pixels(center),
+                radius,
+                color_to_u32(color),
+                0, // Choose the number of segments automatically
+                thickness,
+            );
+        }
+    }
+
+    /// Draw a filled circle. The radius is given in pixels, so the circle keeps its size when
+    /// zooming.
+    pub fn add_circle_filled(&self, center: ImPlotPoint, radius: f32, color: ImVec4) {
+        unsafe {
+            imgui::sys::ImDrawList_AddCircleFilled(
+                self.raw,
+                to_pixels(center),
+                radius,
+                color_to_u32(color),
+                0, // Choose the number of segments automatically
+            );
+        }
+    }
+
+    /// Draw the outline of a triangle.
+    pub fn add_triangle(
+        &self,
+        p1: ImPlotPoint,
+        p2: ImPlotPoint,
+        p3: ImPlotPoint,
+        color: ImVec4,
+        thickness: f32,
+    ) {
+        unsafe {
+            imgui::sys::ImDrawList_AddTriangle(
+                self.raw,
+                to_pixels(p1),
+                to_pixels(p2),
+                to_pixels(p3),
+                color_to_u32(color),
+                thickness,
+            );
+        }
+    }
+
+    /// Draw a filled triangle.
+    pub fn add_triangle_filled(
+        &self,
+        p1: ImPlotPoint,
+        p2: ImPlotPoint,
+        p3: ImPlotPoint,
+        color: ImVec4,
+    ) {
+        unsafe {
+            imgui::sys::ImDrawList_AddTriangleFilled(
+                self.raw,
+                to_pixels(p1),
+                to_pixels(p2),
+                to_pixels(p3),
+                color_to_u32(color),
+            );
+        }
+    }
+
+    /// Draw text with its top left corner at the given position.
+    pub fn add_text(&self, position: ImPlotPoint, color: ImVec4, text: &str) {
+        let text_begin = text.as_ptr() as *const c_char;
+        unsafe {
+            imgui::sys::ImDrawList_AddText_Vec2(
+                self.raw,
+                to_pixels(position),
+                color_to_u32(color),
+                text_begin,
+                text_begin.add(text.len()),
+            );
+        }
+    }
+}
+
+impl Drop for PlotDrawList<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            sys::ImPlot_PopPlotClipRect();
+        }
+    }
+}
+
+/// Convert a point in plot coordinates to pixels, using the currently selected Y axis.
+fn to_pixels(point: ImPlotPoint) -> ImVec2 {
+    plot_to_pixels_vec2(&point, None)
+}
+
+/// Convert two corners in plot coordinates to the minimum and maximum corners in pixels, since
+/// axes can be inverted and the pixel Y axis points down.
+fn pixel_rect(p1: ImPlotPoint, p2: ImPlotPoint) -> (ImVec2, ImVec2) {
+    let p1 = to_pixels(p1);
+    let p2 = to_pixels(p2);
+    (
+        ImVec2 {
+            x: p1.x.min(p2.x),
+            y: p1.y.min(p2.y),
+        },
+        ImVec2 {
+            x: p1.x.max(p2.x),
+            y: p1.y.max(p2.y),
+        },
+    )
+}
+
+/// Convert a color to the packed format used by imgui draw lists.
+fn color_to_u32(color: ImVec4) -> u32 {
+    unsafe { imgui::sys::igColorConvertFloat4ToU32(color) }
+}
@@ -0,0 +1,105 @@
+//! # Getter module
+//!
+//! This module contains the glue for the `*G` plotting functions of the C++ API, which ask a
+//! callback for each point instead of reading it from memory. Rust closures are passed to them
+//! through the `data` pointer, together with a trampoline function that calls the closure.
+
+use std::any::Any;
+use std::os::raw::{c_int, c_void};
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::ImPlotPoint;
+
+/// Getter function type of `PlotStairsG`, which returns the point by value.
+pub(crate) type Getter = unsafe extern "C" fn(data: *mut c_void, idx: c_int) -> ImPlotPoint;
+
+/// Getter function type of the other `*G` functions, which return a pointer to the point. The
+/// point has to stay valid until the getter is called the next time.
+pub(crate) type PointerGetter =
+    unsafe extern "C" fn(data: *mut c_void, idx: c_int) -> *mut ImPlotPoint;
+
+/// A closure that computes the point at a given index, along with a slot for a panic raised by
+/// it. Panics must not unwind into the C++ code, so they are caught in the trampoline and
+/// resumed by [`PointGetter::finish`] once control is back on the Rust side.
+pub(crate) struct PointGetter<F> {
+    /// The closure computing the points
+    getter: F,
+    /// The point computed last, which [`PointerGetter`] trampolines return a pointer to
+    point: ImPlotPoint,
+    /// Payload of a panic raised by the closure, if any
+    panic_payload: Option<Box<dyn Any + Send + 'static>>,
+}
+
+impl<F: FnMut(usize) -> ImPlotPoint> PointGetter<F> {
+    /// Wrap a closure so it can be passed to the C++ API.
+    pub(crate) fn new(getter: F) -> Self {
+        Self {
+            getter,
+            point: ImPlotPoint { x: 0.0, y: 0.0 },
+            panic_payload: None,
+        }
+    }
+
+    /// The trampoline function to pass as a [`Getter`].
+    pub(crate) fn function(&self) -> Option<Getter> {
+        Some(trampoline::<F>)
+    }
+
+    /// The trampoline function to pass as a [`PointerGetter`].
+    pub(crate) fn pointer_function(&self) -> Option<PointerGetter> {
+        Some(pointer_trampoline::<F>)
+    }
+
+    /// The pointer to pass as the `data` argument along with the trampoline function.
+    pub(crate) fn data(&mut self) -> *mut c_void {
+        self as *mut Self as *mut c_void
+    }
+
+    /// Resume a panic raised by the closure while the C++ code was calling it.
+    pub(crate) fn finish(self) {
+        if let Some(payload) = self.panic_payload {
+            panic::resume_unwind(payload);
+        }
+    }
+
+    /// Compute the point at the given index. Once the closure has panicked, it is not called
+    /// again, and all remaining points are NaN, which ImPlot skips when fitting.
+    fn compute(&mut self, index: c_int) -> ImPlotPoint {
+        let nan_point = ImPlotPoint {
+            x: f64::NAN,
+            y: f64::NAN,
+        };
+        if self.panic_payload.is_some() {
+            return nan_point;
+        }
+        let getter = &mut self.getter;
+        // Indices passed by ImPlot are always in 0..count, so the cast can't go wrong.
+        match panic::catch_unwind(AssertUnwindSafe(|| getter(index as usize))) {
+            Ok(point) => point,
+            Err(payload) => {
+                self.panic_payload = Some(payload);
+                nan_point
+            }
+        }
+    }
+}
+
+/// Called by the C++ code for every point, returning it by value.
+unsafe extern "C" fn trampoline<F: FnMut(usize) -> ImPlotPoint>(
+    data: *mut c_void,
+    index: c_int,
+) -> ImPlotPoint {
+    let state = &mut *(data as *mut PointGetter<F>);
+    state.compute(index)
+}
+
+/// Called by the C++ code for every point, returning a pointer to it. The point is stored in
+/// the getter state, which outlives the C++ call, and is only overwritten by the next call.
+unsafe extern "C" fn pointer_trampoline<F: FnMut(usize) -> ImPlotPoint>(
+    data: *mut c_void,
+    index: c_int,
+) -> *mut ImPlotPoint {
+    let state = &mut *(data as *mut PointGetter<F>);
+    state.point = state.compute(index);
+    &mut state.point
+}
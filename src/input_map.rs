@@ -0,0 +1,201 @@
+//! # Input map module
+//!
+//! This module defines [`InputMap`], which configures which mouse buttons and key modifiers
+//! trigger the interactive features of plots such as panning, box selection and queries. The
+//! input map of a context is read and written through [`Context::input_map`] and
+//! [`Context::set_input_map`].
+
+use bitflags::bitflags;
+use imgui::MouseButton;
+
+use crate::{sys, Context};
+
+bitflags! {
+    /// Key modifiers that have to be held for a plot interaction, see [`InputMap`].
+    #[repr(transparent)]
+    pub struct KeyModFlags: u32 {
+        /// No modifier
+        const NONE = imgui::sys::ImGuiKeyModFlags_None;
+        /// Ctrl key
+        const CTRL = imgui::sys::ImGuiKeyModFlags_Ctrl;
+        /// Shift key
+        const SHIFT = imgui::sys::ImGuiKeyModFlags_Shift;
+        /// Alt key
+        const ALT = imgui::sys::ImGuiKeyModFlags_Alt;
+        /// Super key (Cmd on macOS, Windows key on Windows)
+        const SUPER = imgui::sys::ImGuiKeyModFlags_Super;
+    }
+}
+
+/// Mapping of mouse buttons and key modifiers to plot interactions. Modifiers set to
+/// `KeyModFlags::NONE` mean no modifier is required. The [`Default`] implementation returns
+/// ImPlot's default mapping.
+#[rustversion::attr(since(1.48), doc(alias = "ImPlotInputMap"))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct InputMap {
+    /// Button for panning a plot (left by default)
+    pub pan_button: MouseButton,
+    /// Modifier that must be held for panning
+    pub pan_mod: KeyModFlags,
+    /// Button that fits the plot to its data when double-clicked (left by default)
+    pub fit_button: MouseButton,
+    /// Button that opens the context menu when clicked (right by default)
+    pub context_menu_button: MouseButton,
+    /// Button for box selection, which zooms to the selected box (right by default)
+    pub box_select_button: MouseButton,
+    /// Modifier that must be held for box selection
+    pub box_select_mod: KeyModFlags,
+    /// Button that cancels an active box selection (left by default)
+    pub box_select_cancel_button: MouseButton,
+    /// Button for making a query, if queries are enabled for the plot (middle by default)
+    pub query_button: MouseButton,
+    /// Modifier that must be held for making a query
+    pub query_mod: KeyModFlags,
+    /// Modifier that, when held, makes a new query add to the current one (ctrl by default)
+    pub query_toggle_mod: KeyModFlags,
+    /// Modifier that restricts selections and queries to the horizontal direction (alt by
+    /// default)
+    pub horizontal_mod: KeyModFlags,
+    /// Modifier that restricts selections and queries to the vertical direction (shift by
+    /// default)
+    pub vertical_mod: KeyModFlags,
+}
+
+impl InputMap {
+    /// ImPlot's default input mapping, as described in the field documentation.
+    pub fn implot_default() -> Self {
+        unsafe {
+            let raw = sys::ImPlotInputMap_ImPlotInputMap();
+            assert_ne!(raw, std::ptr::null_mut());
+            let input_map = Self::from_raw(&*raw);
+            sys::ImPlotInputMap_destroy(raw);
+            input_map
+        }
+    }
+
+    /// An input mapping closer to matplotlib's zoom and pan tools: left-dragging zooms to a box
+    /// and right-dragging pans, the reverse of ImPlot's default. A right click without dragging
+    /// still opens the context menu, the right button cancels a box selection and the middle
+    /// button makes queries. Double-clicking the left button fits the plot, and the modifiers
+    /// are the same as in the default mapping.
+    pub fn matplotlib_like() -> Self {
+        Self {
+            pan_button: MouseButton::Right,
+            pan_mod: KeyModFlags::NONE,
+            fit_button: MouseButton::Left,
+            context_menu_button: MouseButton::Right,
+            box_select_button: MouseButton::Left,
+            box_select_mod: KeyModFlags::NONE,
+            box_select_cancel_button: MouseButton::Right,
+            query_button: MouseButton::Middle,
+            query_mod: KeyModFlags::NONE,
+            query_toggle_mod: KeyModFlags::CTRL,
+            horizontal_mod: KeyModFlags::ALT,
+            vertical_mod: KeyModFlags::SHIFT,
+        }
+    }
+
+    /// An input mapping in the style of CAD applications: the middle button pans and fits,
+    /// shift and left-dragging selects a box and ctrl and right-dragging makes a query. The
+    /// direction restricting modifiers are shift+alt (horizontal) and shift+ctrl (vertical),
+    /// so they can be combined with box selection.
+    pub fn cad_like() -> Self {
+        Self {
+            pan_button: MouseButton::Middle,
+            pan_mod: KeyModFlags::NONE,
+            fit_button: MouseButton::Middle,
+            context_menu_button: MouseButton::Right,
+            box_select_button: MouseButton::Left,
+            box_select_mod: KeyModFlags::SHIFT,
+            box_select_cancel_button: MouseButton::Right,
+            query_button: MouseButton::Right,
+            query_mod: KeyModFlags::CTRL,
+            query_toggle_mod: KeyModFlags::CTRL | KeyModFlags::ALT,
+            horizontal_mod: KeyModFlags::SHIFT | KeyModFlags::ALT,
+            vertical_mod: KeyModFlags::SHIFT | KeyModFlags::CTRL,
+        }
+    }
+
+    /// Reset this input map to ImPlot's default mapping.
+    pub fn reset_to_defaults(&mut self) {
+        *self = Self::implot_default();
+    }
+
+    /// Convert from the raw input map struct.
+    fn from_raw(raw: &sys::ImPlotInputMap) -> Self {
+        Self {
+            pan_button: mouse_button_from_raw(raw.PanButton),
+            pan_mod: key_mod_flags_from_raw(raw.PanMod),
+            fit_button: mouse_button_from_raw(raw.FitButton),
+            context_menu_button: mouse_button_from_raw(raw.ContextMenuButton),
+            box_select_button: mouse_button_from_raw(raw.BoxSelectButton),
+            box_select_mod: key_mod_flags_from_raw(raw.BoxSelectMod),
+            box_select_cancel_button: mouse_button_from_raw(raw.BoxSelectCancelButton),
+            query_button: mouse_button_from_raw(raw.QueryButton),
+            query_mod: key_mod_flags_from_raw(raw.QueryMod),
+            query_toggle_mod: key_mod_flags_from_raw(raw.QueryToggleMod),
+            horizontal_mod: key_mod_flags_from_raw(raw.HorizontalMod),
+            vertical_mod: key_mod_flags_from_raw(raw.VerticalMod),
+        }
+    }
+
+    /// Write this input map into the raw input map struct.
+    fn write_to_raw(&self, raw: &mut sys::ImPlotInputMap) {
+        // "as" casts saturate as of Rust 1.45. This is safe here, since both the buttons and
+        // the modifier flags are small values.
+        raw.PanButton = self.pan_button as sys::ImGuiMouseButton;
+        raw.PanMod = self.pan_mod.bits() as sys::ImGuiKeyModFlags;
+        raw.FitButton = self.fit_button as sys::ImGuiMouseButton;
+        raw.ContextMenuButton = self.context_menu_button as sys::ImGuiMouseButton;
+        raw.BoxSelectButton = self.box_select_button as sys::ImGuiMouseButton;
+        raw.BoxSelectMod = self.box_select_mod.bits() as sys::ImGuiKeyModFlags;
+        raw.BoxSelectCancelButton = self.box_select_cancel_button as sys::ImGuiMouseButton;
+        raw.QueryButton = self.query_button as sys::ImGuiMouseButton;
+        raw.QueryMod = self.query_mod.bits() as sys::ImGuiKeyModFlags;
+        raw.QueryToggleMod = self.query_toggle_mod.bits() as sys::ImGuiKeyModFlags;
+        raw.HorizontalMod = self.horizontal_mod.bits() as sys::ImGuiKeyModFlags;
+        raw.VerticalMod = self.vertical_mod.bits() as sys::ImGuiKeyModFlags;
+    }
+}
+
+impl Default for InputMap {
+    fn default() -> Self {
+        Self::implot_default()
+    }
+}
+
+impl Context {
+    /// Get the input map of this context.
+    #[rustversion::attr(since(1.48), doc(alias = "GetInputMap"))]
+    pub fn input_map(&self) -> InputMap {
+        unsafe {
+            let raw = sys::ImPlot_GetInputMap();
+            assert_ne!(raw, std::ptr::null_mut());
+            InputMap::from_raw(&*raw)
+        }
+    }
+
+    /// Set the input map of this context, which is used for all plots from then on.
+    #[rustversion::attr(since(1.48), doc(alias = "GetInputMap"))]
+    pub fn set_input_map(&mut self, input_map: &InputMap) {
+        unsafe {
+            let raw = sys::ImPlot_GetInputMap();
+            assert_ne!(raw, std::ptr::null_mut());
+            input_map.write_to_raw(&mut *raw);
+        }
+    }
+}
+
+/// Convert a raw mouse button value, falling back to the left button for unknown values.
+fn mouse_button_from_raw(raw: sys::ImGuiMouseButton) -> MouseButton {
+    MouseButton::VARIANTS
+        .iter()
+        .copied()
+        .find(|&button| button as sys::ImGuiMouseButton == raw)
+        .unwrap_or(MouseButton::Left)
+}
+
+/// Convert raw key modifier flags, ignoring unknown bits.
+fn key_mod_flags_from_raw(raw: sys::ImGuiKeyModFlags) -> KeyModFlags {
+    KeyModFlags::from_bits_truncate(raw as u32)
+}
@@ -0,0 +1,259 @@
+//! # Legend module
+//!
+//! This module contains functionality for interacting with the legend entries of plot items,
+//! such as custom context menus that open when clicking on a legend entry, or dragging legend
+//! entries to drop targets.
+use crate::sys;
+use imgui::{DragDropFlags, DragDropTarget, MouseButton};
+use std::any::TypeId;
+use std::ffi::{c_void, CString};
+use std::os::raw::c_char;
+
+// Maximum length of a drag and drop payload name, this has to match the size of the
+// DataType field of ImGuiPayload in imgui.h (minus the null terminator).
+const MAX_PAYLOAD_NAME_LENGTH: usize = 32;
+
+// Prefix added to the names of legend payloads. The payloads are laid out differently from the
+// ones set by imgui-rs, so the prefix keeps imgui-rs' typed `accept_payload` from matching them.
+const PAYLOAD_NAME_PREFIX: &str = "implot:";
+
+/// Get the internal name of a legend payload with the given name.
+///
+/// # Panics
+/// Will panic if the name is too long to fit into imgui's payload name along with the prefix.
+fn legend_payload_name(name: &str) -> String {
+    assert!(
+        PAYLOAD_NAME_PREFIX.len() + name.len() <= MAX_PAYLOAD_NAME_LENGTH,
+        "Payload name is longer than {} bytes: {}",
+        MAX_PAYLOAD_NAME_LENGTH - PAYLOAD_NAME_PREFIX.len(),
+        name
+    );
+    format!("{}{}", PAYLOAD_NAME_PREFIX, name)
+}
+
+/// Attempt to open a popup for the legend entry with the given label, which is shown when the
+/// entry is clicked with the given mouse button. If this returns a token, the popup is open,
+/// and its contents can be built with regular imgui widgets. Call `end()` on the token when done.
+/// Use this within a plot, after the item with the given label was plotted.
+///
+/// For a convenient implementation of all this, use [`build_legend_popup`] instead.
+///
+/// # Panics
+/// Will panic if the label string contains internal null bytes.
+#[rustversion::attr(since(1.48), doc(alias = "BeginLegendPopup"))]
+pub fn begin_legend_popup(label: &str, mouse_button: MouseButton) -> Option<LegendPopupToken> {
+    let label = CString::new(label)
+        .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label));
+    let is_open = unsafe {
+        sys::ImPlot_BeginLegendPopup(
+            label.as_ptr() as *const c_char,
+            mouse_button as sys::ImGuiMouseButton,
+        )
+    };
+
+    if is_open {
+        Some(LegendPopupToken {
+            label,
+            was_ended: false,
+        })
+    } else {
+        // Like with plots, end() does not have to be called if the popup is not open.
+        None
+    }
+}
+
+/// Creates a popup for the legend entry with the given label, and runs a closure to construct
+/// its contents if it is open. This internally calls `begin_legend_popup` and `end`.
+#[rustversion::attr(since(1.48), doc(alias = "BeginLegendPopup"))]
+#[rustversion::attr(since(1.48), doc(alias = "EndLegendPopup"))]
+pub fn build_legend_popup<F: FnOnce()>(label: &str, mouse_button: MouseButton, f: F) {
+    if let Some(token) = begin_legend_popup(label, mouse_button) {
+        f();
+        token.end()
+    }
+}
+
+/// Tracks a legend popup that must be ended by calling `.end()`
+pub struct LegendPopupToken {
+    /// For better error messages
+    label: CString,
+    /// Whether this token has been ended or not.
+    was_ended: bool,
+}
+
+impl LegendPopupToken {
+    /// End a previously opened legend popup.
+    #[rustversion::attr(since(1.48), doc(alias = "EndLegendPopup"))]
+    pub fn end(mut self) {
+        self.was_ended = true;
+        unsafe { sys::ImPlot_EndLegendPopup() };
+    }
+}
+
+impl Drop for LegendPopupToken {
+    fn drop(&mut self) {
+        if !self.was_ended && !std::thread::panicking() {
+            panic!(
+                "Warning: A LegendPopupToken for legend entry \"{:?}\" was not called end() on",
+                self.label
+            );
+        }
+    }
+}
+
+/// Attempt to make the legend entry with the given label a drag and drop source. If this returns
+/// a token, the entry is being dragged - set a payload with
+/// [`LegendDragDropSourceToken::set_payload`], optionally build the contents of the tooltip that
+/// is shown while dragging with regular imgui widgets, and call `end()` on the token when done.
+/// Use this within a plot, after the item with the given label was plotted.
+///
+/// For a convenient implementation of all this, use [`build_legend_drag_drop_source`] instead.
+///
+/// # Panics
+/// Will panic if the label string contains internal null bytes.
+#[rustversion::attr(since(1.48), doc(alias = "BeginLegendDragDropSource"))]
+pub fn begin_legend_drag_drop_source(
+    label: &str,
+    flags: DragDropFlags,
+) -> Option<LegendDragDropSourceToken> {
+    let label = CString::new(label)
+        .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label));
+    let is_dragged = unsafe {
+        sys::ImPlot_BeginLegendDragDropSource(
+            label.as_ptr() as *const c_char,
+            flags.bits() as sys::ImGuiDragDropFlags,
+        )
+    };
+
+    if is_dragged {
+        Some(LegendDragDropSourceToken {
+            label,
+            was_ended: false,
+        })
+    } else {
+        // Like with plots, end() does not have to be called if nothing is being dragged.
+        None
+    }
+}
+
+/// Makes the legend entry with the given label a drag and drop source, and runs a closure while
+/// it is being dragged. The closure gets the source token to set the payload with. This
+/// internally calls `begin_legend_drag_drop_source` and `end`.
+///
+/// ```no_run
+/// # use implot::{build_legend_drag_drop_source, PlotLine};
+/// # use imgui::DragDropFlags;
+/// # let series_id: usize = 3;
+/// // Within a plot
+/// PlotLine::new("series").plot(&[0.0, 1.0], &[0.0, 1.0]);
+/// build_legend_drag_drop_source("series", DragDropFlags::empty(), |source| {
+///     source.set_payload("SERIES_ID", series_id);
+/// });
+/// ```
+#[rustversion::attr(since(1.48), doc(alias = "BeginLegendDragDropSource"))]
+#[rustversion::attr(since(1.48), doc(alias = "EndLegendDragDropSource"))]
+pub fn build_legend_drag_drop_source<F: FnOnce(&LegendDragDropSourceToken)>(
+    label: &str,
+    flags: DragDropFlags,
+    f: F,
+) {
+    if let Some(token) = begin_legend_drag_drop_source(label, flags) {
+        f(&token);
+        token.end()
+    }
+}
+
+/// Payload layout used by [`LegendDragDropSourceToken::set_payload`]. The type ID is stored
+/// along with the data so that the receiving side can check it is reading the right type.
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct TypedLegendPayload<T: Copy + 'static> {
+    type_id: TypeId,
+    data: T,
+}
+
+/// Tracks a legend drag and drop source that must be ended by calling `.end()`
+pub struct LegendDragDropSourceToken {
+    /// For better error messages
+    label: CString,
+    /// Whether this token has been ended or not.
+    was_ended: bool,
+}
+
+impl LegendDragDropSourceToken {
+    /// Set the payload that is dragged, under the given payload name. Drop targets only accept
+    /// payloads with names they are looking for. The payload is copied, so it should be a small
+    /// value such as an identifier for the dragged series. To receive it, use
+    /// [`accept_legend_payload`] with the same name and type on an imgui-rs `DragDropTarget`.
+    /// The payload is stored in a format of this crate under an internal name, so only
+    /// [`accept_legend_payload`] can decode it, not imgui-rs' own `accept_payload` functions.
+    ///
+    /// # Panics
+    /// Will panic if the name is longer than 25 bytes (a limitation of imgui, minus the space
+    /// taken by the internal name prefix) or contains internal null bytes.
+    #[rustversion::attr(since(1.48), doc(alias = "SetDragDropPayload"))]
+    pub fn set_payload<T: Copy + 'static>(&self, name: &str, payload: T) {
+        let name = CString::new(legend_payload_name(name))
+            .unwrap_or_else(|_| panic!("Payload name has internal null bytes: {}", name));
+        let payload = TypedLegendPayload {
+            type_id: TypeId::of::<T>(),
+            data: payload,
+        };
+        unsafe {
+            // imgui copies the payload data into its own buffer, so the local is fine here.
+            imgui::sys::igSetDragDropPayload(
+                name.as_ptr(),
+                &payload as *const _ as *const c_void,
+                std::mem::size_of::<TypedLegendPayload<T>>(),
+                0, // Always update the payload, this is ImGuiCond_Always
+            );
+        }
+    }
+
+    /// End a previously started legend drag and drop source.
+    #[rustversion::attr(since(1.48), doc(alias = "EndLegendDragDropSource"))]
+    pub fn end(mut self) {
+        self.was_ended = true;
+        unsafe { sys::ImPlot_EndLegendDragDropSource() };
+    }
+}
+
+impl Drop for LegendDragDropSourceToken {
+    fn drop(&mut self) {
+        if !self.was_ended && !std::thread::panicking() {
+            panic!(
+                "Warning: A LegendDragDropSourceToken for legend entry \"{:?}\" was not called \
+                 end() on",
+                self.label
+            );
+        }
+    }
+}
+
+/// Accept a payload set with [`LegendDragDropSourceToken::set_payload`] on an imgui-rs drag and
+/// drop target. Returns the payload once it is dropped onto the target (or also while it is
+/// hovering if `DragDropFlags::ACCEPT_BEFORE_DELIVERY` is given), and `None` otherwise, including
+/// when the payload with the given name is of a different type than `T`.
+///
+/// # Panics
+/// Will panic if the name is longer than 25 bytes, like [`LegendDragDropSourceToken::set_payload`].
+pub fn accept_legend_payload<T: Copy + 'static>(
+    target: &DragDropTarget,
+    name: &str,
+    flags: DragDropFlags,
+) -> Option<T> {
+    // The payload data is only valid during this call, so it is copied out right away.
+    let name = legend_payload_name(name);
+    let payload = unsafe { target.accept_payload_unchecked(name, flags)? };
+    if payload.data.is_null() || payload.size != std::mem::size_of::<TypedLegendPayload<T>>() {
+        return None;
+    }
+    // The type ID is at the start of the payload (it is repr(C)), and it is checked before the
+    // data itself is read so that no invalid values of T are ever created.
+    let type_id = unsafe { std::ptr::read_unaligned(payload.data as *const TypeId) };
+    if type_id != TypeId::of::<T>() {
+        return None;
+    }
+    let payload = unsafe { std::ptr::read_unaligned(payload.data as *const TypedLegendPayload<T>) };
+    Some(payload.data)
+}
@@ -19,18 +19,49 @@
 use implot_sys as sys;
 
 // TODO(4bb4) facade-wrap these?
-pub use self::{context::*, plot::*, plot_elements::*};
+pub use self::{
+    context::*, draw_list::*, input_map::*, legend::*, plot::*, plot_elements::*, plot_tools::*,
+    strided::*, style::*,
+};
+
+#[cfg(feature = "ndarray")]
+pub use self::array_views::*;
+#[cfg(feature = "serde")]
+pub use self::theme::*;
+use parking_lot::Mutex;
+use std::ffi::CString;
 use std::os::raw::c_char;
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
 pub use sys::{ImPlotLimits, ImPlotPoint, ImPlotRange, ImVec2, ImVec4};
 
+#[cfg(feature = "ndarray")]
+mod array_views;
 mod context;
+mod draw_list;
+mod getter;
+mod input_map;
+mod legend;
 mod plot;
 mod plot_elements;
+mod plot_tools;
+mod strided;
+mod style;
+#[cfg(feature = "serde")]
+mod theme;
 
 // The bindings for some reason don't contain this - it has to match the IMPLOT_AUTO from
 // the original C++ header for things to work properly.
 const IMPLOT_AUTO: i32 = -1;
 
+// Same as IMPLOT_AUTO, but for colors - this is IMPLOT_AUTO_COL in the original C++ header,
+// which makes implot pick the color from the current style or colormap.
+const IMPLOT_AUTO_COLOR: ImVec4 = ImVec4 {
+    x: 0.0,
+    y: 0.0,
+    z: 0.0,
+    w: -1.0,
+};
+
 // Number of Y axes, this is used in a bunch of places for storing things like settings.
 // If this changes, also change the YAxisChoice enum.
 const NUMBER_OF_Y_AXES: usize = 3;
@@ -71,6 +102,7 @@ pub struct PlotUi<'ui> {
 #[rustversion::attr(since(1.48), doc(alias = "ImPlotMarker"))]
 #[repr(i32)]
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Marker {
     /// no marker
     None = sys::ImPlotMarker__ImPlotMarker_None,
@@ -101,7 +133,8 @@ pub enum Marker {
 /// be colored - hence I added the "Element".
 #[rustversion::attr(since(1.48), doc(alias = "ImPlotCol"))]
 #[repr(u32)]
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PlotColorElement {
     /// Plot line/outline color (defaults to next unused color in current colormap)
     Line = sys::ImPlotCol__ImPlotCol_Line,
@@ -157,6 +190,7 @@ pub enum PlotColorElement {
 #[rustversion::attr(since(1.48), doc(alias = "ImPlotColormap"))]
 #[repr(u32)]
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Colormap {
     /// ImPlot default colormap (n=10). Called "Standard" here because Default is reserved.
     Standard = sys::ImPlotColormap__ImPlotColormap_Default,
@@ -288,6 +322,7 @@ pub fn set_colormap_from_preset(preset: Colormap, samples: u32) {
         // risk of changed values.
         sys::ImPlot_SetColormapPlotColormap(preset as i32, samples as i32);
     }
+    set_current_colormap_preset(Some(preset));
 }
 
 /// Set a custom colormap in the form of a vector of colors.
@@ -296,6 +331,303 @@ pub fn set_colormap_from_vec(colors: Vec<ImVec4>) {
     unsafe {
         sys::ImPlot_SetColormapVec4Ptr(colors.as_ptr(), colors.len() as i32);
     }
+    set_current_colormap_preset(None);
+}
+
+// Marks that a custom colormap is set instead of a preset
+const CUSTOM_COLORMAP: u32 = u32::MAX;
+
+// Colormap preset that was last set through this crate, or CUSTOM_COLORMAP. ImPlot has no way to
+// query this, so it is tracked here for saving it in themes. Only one context can exist at a
+// time, so a single value is enough.
+static CURRENT_COLORMAP_PRESET: AtomicU32 = AtomicU32::new(Colormap::Standard as u32);
+
+/// Remember the colormap preset that was set last, or `None` for a custom colormap.
+pub(crate) fn set_current_colormap_preset(preset: Option<Colormap>) {
+    let raw = preset.map_or(CUSTOM_COLORMAP, |preset| preset as u32);
+    CURRENT_COLORMAP_PRESET.store(raw, Ordering::SeqCst);
+}
+
+/// Get the colormap preset that was set last through [`set_colormap_from_preset`],
+/// [`show_colormap_selector`] or by applying a theme, which is [`Colormap::Standard`] for a new
+/// context. Returns `None` if a custom colormap was set through [`set_colormap_from_vec`] since.
+/// Colormaps pushed through [`ColormapToken`]s are not reflected, see [`CurrentColormap::preset`]
+/// for that.
+pub fn current_colormap_preset() -> Option<Colormap> {
+    let raw = CURRENT_COLORMAP_PRESET.load(Ordering::SeqCst);
+    ALL_COLORMAPS
+        .iter()
+        .copied()
+        .find(|&preset| preset as u32 == raw)
+}
+
+/// All colormap presets, in the order ImPlot defines them.
+const ALL_COLORMAPS: [Colormap; 11] = [
+    Colormap::Standard,
+    Colormap::Deep,
+    Colormap::Dark,
+    Colormap::Pastel,
+    Colormap::Paired,
+    Colormap::Viridis,
+    Colormap::Plasma,
+    Colormap::Hot,
+    Colormap::Cool,
+    Colormap::Pink,
+    Colormap::Jet,
+];
+
+/// Find the preset that the current colormap was set to, by comparing its colors with the ones
+/// of each preset.
+fn identify_colormap_preset() -> Option<Colormap> {
+    let size = CurrentColormap.size();
+    let colors: Vec<ImVec4> = (0..size)
+        .map(|index| CurrentColormap.color(index))
+        .collect();
+    ALL_COLORMAPS.iter().copied().find(|&preset| {
+        with_colormap_preset(preset, || {
+            CurrentColormap.size() == size
+                && (0..size).all(|index| CurrentColormap.color(index) == colors[index as usize])
+        })
+    })
+}
+
+/// Temporarily switch to one of the built-in preset colormaps. The previous colormap is restored
+/// when the returned token is popped or dropped:
+/// ```no_run
+/// # use implot::{push_colormap_from_preset, Colormap};
+/// let colormap = push_colormap_from_preset(Colormap::Viridis);
+/// // Plot some things
+/// colormap.pop();
+/// ```
+#[rustversion::attr(since(1.48), doc(alias = "PushColormap"))]
+pub fn push_colormap_from_preset(preset: Colormap) -> ColormapToken {
+    unsafe {
+        // "as" casts saturate as of Rust 1.45. This is safe here, the enum values are small.
+        sys::ImPlot_PushColormapPlotColormap(preset as i32);
+    }
+    ColormapToken::pushed(Some(preset), None)
+}
+
+/// Temporarily switch to a custom colormap given as a vector of colors. The token keeps the
+/// colors alive, since ImPlot does not copy them, and restores the previous colormap when it
+/// is popped or dropped.
+#[rustversion::attr(since(1.48), doc(alias = "PushColormap"))]
+pub fn push_colormap_from_vec(colors: Vec<ImVec4>) -> ColormapToken {
+    assert!(!colors.is_empty(), "A colormap needs at least one color");
+    unsafe {
+        // "as" casts saturate as of Rust 1.45. This is safe here.
+        sys::ImPlot_PushColormapVec4Ptr(colors.as_ptr(), colors.len() as i32);
+    }
+    ColormapToken::pushed(None, Some(colors))
+}
+
+// Colormaps pushed through tokens that are still on ImPlot's colormap stack, from bottom to top,
+// as the ID of the token and the preset if it is one. Only one context can exist at a time, so
+// a single stack is enough.
+static COLORMAP_STACK: Mutex<Vec<(usize, Option<Colormap>)>> = parking_lot::const_mutex(Vec::new());
+
+// ID of the next colormap token to be created
+static NEXT_COLORMAP_TOKEN_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Forget all pushed colormaps, for a new context whose colormap stack is empty.
+pub(crate) fn clear_colormap_stack() {
+    COLORMAP_STACK.lock().clear();
+}
+
+/// Tracks a colormap pushed to the colormap stack. Unlike the style tokens, this pops the
+/// colormap automatically when dropped.
+///
+/// Tokens should be popped in reverse order of pushing. If one is popped earlier, the colormaps
+/// pushed after it are popped along with it, and popping their tokens later does nothing. In the
+/// same way, a token that was leaked with `std::mem::forget` is popped along with the next token
+/// pushed before it.
+#[must_use = "the colormap is popped again as soon as the token is dropped"]
+pub struct ColormapToken {
+    /// Identifies the entry of this token on the colormap stack
+    id: usize,
+    /// Colors of a custom colormap, which have to stay alive while the colormap is in use
+    _colors: Option<Vec<ImVec4>>,
+}
+
+impl ColormapToken {
+    /// Create the token for a colormap that was just pushed.
+    fn pushed(preset: Option<Colormap>, colors: Option<Vec<ImVec4>>) -> Self {
+        let id = NEXT_COLORMAP_TOKEN_ID.fetch_add(1, Ordering::SeqCst);
+        COLORMAP_STACK.lock().push((id, preset));
+        Self {
+            id,
+            _colors: colors,
+        }
+    }
+
+    /// Restore the colormap that was in use before this one was pushed.
+    #[rustversion::attr(since(1.48), doc(alias = "PopColormap"))]
+    pub fn pop(mut self) {
+        self.pop_internal();
+    }
+
+    fn pop_internal(&mut self) {
+        let mut stack = COLORMAP_STACK.lock();
+        // If the entry is gone, this token was popped already, possibly along with an earlier one
+        if let Some(position) = stack.iter().position(|&(id, _)| id == self.id) {
+            let count = stack.len() - position;
+            stack.truncate(position);
+            unsafe {
+                // "as" casts saturate as of Rust 1.45. This is safe here.
+                sys::ImPlot_PopColormap(count as i32);
+            }
+        }
+    }
+}
+
+impl Drop for ColormapToken {
+    fn drop(&mut self) {
+        self.pop_internal();
+    }
+}
+
+/// Run the given function with a preset temporarily pushed as the current colormap.
+fn with_colormap_preset<T>(preset: Colormap, function: impl FnOnce() -> T) -> T {
+    unsafe {
+        // "as" casts saturate as of Rust 1.45. This is safe here, the enum values are small.
+        sys::ImPlot_PushColormapPlotColormap(preset as i32);
+    }
+    let result = function();
+    unsafe {
+        sys::ImPlot_PopColormap(1);
+    }
+    result
+}
+
+impl Colormap {
+    /// Get the name of this colormap preset.
+    #[rustversion::attr(since(1.48), doc(alias = "GetColormapName"))]
+    pub fn name(self) -> &'static str {
+        unsafe {
+            // "as" casts saturate as of Rust 1.45. This is safe here, the enum values are small.
+            let name = sys::ImPlot_GetColormapName(self as i32);
+            if name.is_null() {
+                return "";
+            }
+            // The names are static ASCII strings in ImPlot
+            std::ffi::CStr::from_ptr(name).to_str().unwrap_or("")
+        }
+    }
+
+    /// Get the number of colors in this colormap preset.
+    #[rustversion::attr(since(1.48), doc(alias = "GetColormapSize"))]
+    pub fn size(self) -> u32 {
+        with_colormap_preset(self, || CurrentColormap.size())
+    }
+
+    /// Get the color at the given index of this colormap preset. Indices past the end of the
+    /// colormap wrap around.
+    #[rustversion::attr(since(1.48), doc(alias = "GetColormapColor"))]
+    pub fn color(self, index: u32) -> ImVec4 {
+        with_colormap_preset(self, || CurrentColormap.color(index))
+    }
+
+    /// Get a color from this colormap preset by linearly interpolating between its colors, with
+    /// `t` going from 0.0 (first color) to 1.0 (last color).
+    #[rustversion::attr(since(1.48), doc(alias = "LerpColormap"))]
+    pub fn lerp(self, t: f32) -> ImVec4 {
+        with_colormap_preset(self, || CurrentColormap.lerp(t))
+    }
+
+    /// Get the color of this colormap preset that the next plot item would be assigned, and
+    /// advance the color index of the current plot past it. Only call this within a plot.
+    #[rustversion::attr(since(1.48), doc(alias = "NextColormapColor"))]
+    pub fn next_color(self) -> ImVec4 {
+        with_colormap_preset(self, || CurrentColormap.next_color())
+    }
+}
+
+/// The colormap that is currently in use - the one pushed last through a [`ColormapToken`]
+/// that has not been popped yet, or otherwise the one set through [`set_colormap_from_preset`]
+/// or [`set_colormap_from_vec`]. This offers the same queries as [`Colormap`] does for presets.
+#[derive(Copy, Clone, Debug)]
+pub struct CurrentColormap;
+
+impl CurrentColormap {
+    /// Get the preset the current colormap is, or `None` if it is a custom colormap.
+    pub fn preset(self) -> Option<Colormap> {
+        match COLORMAP_STACK.lock().last() {
+            Some(&(_, preset)) => preset,
+            None => current_colormap_preset(),
+        }
+    }
+
+    /// Get the name of the current colormap, or `None` if it is a custom colormap.
+    #[rustversion::attr(since(1.48), doc(alias = "GetColormapName"))]
+    pub fn name(self) -> Option<&'static str> {
+        self.preset().map(Colormap::name)
+    }
+
+    /// Get the number of colors in the current colormap.
+    #[rustversion::attr(since(1.48), doc(alias = "GetColormapSize"))]
+    pub fn size(self) -> u32 {
+        // "as" casts saturate as of Rust 1.45. This is safe here, sizes are never negative.
+        unsafe { sys::ImPlot_GetColormapSize() as u32 }
+    }
+
+    /// Get the color at the given index of the current colormap. Indices past the end of the
+    /// colormap wrap around.
+    #[rustversion::attr(since(1.48), doc(alias = "GetColormapColor"))]
+    pub fn color(self, index: u32) -> ImVec4 {
+        let mut color = ImVec4 {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            w: 0.0,
+        }; // doesn't seem to have default()
+        unsafe {
+            // "as" casts saturate as of Rust 1.45. This is safe here.
+            sys::ImPlot_GetColormapColor(&mut color as *mut ImVec4, index as i32);
+        }
+        color
+    }
+
+    /// Get a color from the current colormap by linearly interpolating between its colors, with
+    /// `t` going from 0.0 (first color) to 1.0 (last color).
+    #[rustversion::attr(since(1.48), doc(alias = "LerpColormap"))]
+    pub fn lerp(self, t: f32) -> ImVec4 {
+        let mut color = ImVec4 {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            w: 0.0,
+        }; // doesn't seem to have default()
+        unsafe {
+            sys::ImPlot_LerpColormap(&mut color as *mut ImVec4, t);
+        }
+        color
+    }
+
+    /// Get the next unused color of the current colormap, which is the color the next plot item
+    /// would be assigned, and advance to the one after it. Only call this within a plot.
+    #[rustversion::attr(since(1.48), doc(alias = "NextColormapColor"))]
+    pub fn next_color(self) -> ImVec4 {
+        let mut color = ImVec4 {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            w: 0.0,
+        }; // doesn't seem to have default()
+        unsafe {
+            sys::ImPlot_NextColormapColor(&mut color as *mut ImVec4);
+        }
+        color
+    }
+}
+
+/// Show a vertical color scale of the current colormap, going from `scale_min` to `scale_max`
+/// with the given height in pixels. This is meant to be placed next to a plot, for example one
+/// containing a heatmap, and not within a plot.
+#[rustversion::attr(since(1.48), doc(alias = "ShowColormapScale"))]
+pub fn show_colormap_scale(scale_min: f64, scale_max: f64, height: f32) {
+    unsafe {
+        sys::ImPlot_ShowColormapScale(scale_min, scale_max, height);
+    }
 }
 
 // --- Push/pop utils -------------------------------------------------------------------------
@@ -573,6 +905,23 @@ pub fn is_plot_y_axis_hovered(y_axis_choice: Option<YAxisChoice>) -> bool {
     unsafe { sys::ImPlot_IsPlotYAxisHovered(y_axis_choice_i32) }
 }
 
+/// Returns the color of the item that was plotted last in the current plot. Only call this
+/// within a plot, after plotting at least one item - the `plot` functions of plot items also
+/// return this color as part of their result.
+#[rustversion::attr(since(1.48), doc(alias = "GetLastItemColor"))]
+pub fn last_item_color() -> ImVec4 {
+    let mut color = ImVec4 {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+        w: 0.0,
+    }; // doesn't seem to have default()
+    unsafe {
+        sys::ImPlot_GetLastItemColor(&mut color as *mut ImVec4);
+    }
+    color
+}
+
 /// Returns true if the given item in the legend of the current plot is hovered.
 pub fn is_legend_entry_hovered(legend_entry: &str) -> bool {
     unsafe { sys::ImPlot_IsLegendEntryHovered(legend_entry.as_ptr() as *const c_char) }
@@ -589,3 +938,76 @@ pub fn show_demo_window(show: &mut bool) {
         implot_sys::ImPlot_ShowDemoWindow(show);
     }
 }
+
+/// Show the style editor for the live style of the current context. The optional reference
+/// style is what the editor's "Revert" button goes back to and what "Save Ref" writes to - if
+/// it is `None`, ImPlot uses an internal reference style instead. Note that this is not a
+/// window by itself, so it needs to be placed inside an imgui window.
+#[rustversion::attr(since(1.48), doc(alias = "ShowStyleEditor"))]
+pub fn show_style_editor(reference: Option<&mut PlotStyle>) {
+    let reference = match reference {
+        Some(style) => style.raw_mut() as *mut sys::ImPlotStyle,
+        None => std::ptr::null_mut(),
+    };
+    unsafe {
+        sys::ImPlot_ShowStyleEditor(reference);
+    }
+}
+
+/// Show the style editor for a caller-owned style instead of the live style of the current
+/// context, for example one that is only applied once a settings dialog is confirmed. The
+/// style is temporarily swapped into the context while the editor is shown. Like
+/// `show_style_editor`, this needs to be placed inside an imgui window.
+#[rustversion::attr(since(1.48), doc(alias = "ShowStyleEditor"))]
+pub fn show_style_editor_for(style: &mut PlotStyle) {
+    unsafe {
+        let live_style = sys::ImPlot_GetStyle();
+        assert_ne!(live_style, std::ptr::null_mut());
+        let saved_style = *live_style;
+        *live_style = *style.raw();
+        sys::ImPlot_ShowStyleEditor(std::ptr::null_mut());
+        *style.raw_mut() = *live_style;
+        *live_style = saved_style;
+    }
+}
+
+/// Show a combo box for choosing one of the built-in color styles, which is applied to the live
+/// style of the current context. Returns true if a style was selected.
+#[rustversion::attr(since(1.48), doc(alias = "ShowStyleSelector"))]
+pub fn show_style_selector(label: &str) -> bool {
+    let label = CString::new(label)
+        .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label));
+    unsafe { sys::ImPlot_ShowStyleSelector(label.as_ptr()) }
+}
+
+/// Show a combo box for choosing one of the built-in colormaps, which is then set as the current
+/// colormap. Returns true if a colormap was selected.
+#[rustversion::attr(since(1.48), doc(alias = "ShowColormapSelector"))]
+pub fn show_colormap_selector(label: &str) -> bool {
+    let label = CString::new(label)
+        .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label));
+    let selected = unsafe { sys::ImPlot_ShowColormapSelector(label.as_ptr()) };
+    if selected {
+        // The selector doesn't tell which preset was chosen, so it is found by its colors
+        set_current_colormap_preset(identify_colormap_preset());
+    }
+    selected
+}
+
+/// Show the ImPlot metrics window, which shows internal information about the plots. The window
+/// can be closed by the user, in which case `show` is set to false.
+#[rustversion::attr(since(1.48), doc(alias = "ShowMetricsWindow"))]
+pub fn show_metrics_window(show: &mut bool) {
+    unsafe {
+        sys::ImPlot_ShowMetricsWindow(show);
+    }
+}
+
+/// Show the text explaining how to interact with plots. Like `show_style_editor`, this needs
+/// to be placed inside an imgui window.
+#[rustversion::attr(since(1.48), doc(alias = "ShowUserGuide"))]
+pub fn show_user_guide() {
+    unsafe {
+        sys::ImPlot_ShowUserGuide();
+    }
+}
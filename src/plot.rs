@@ -2,7 +2,9 @@
 //!
 //! This module defines the `Plot` struct, which is used to create a 2D plot that will
 //! contain all other objects that can be created using this library.
-use crate::{Context, PlotLocation, PlotOrientation, PlotUi, YAxisChoice, NUMBER_OF_Y_AXES};
+use crate::{
+    Context, PlotDrawList, PlotLocation, PlotOrientation, PlotUi, YAxisChoice, NUMBER_OF_Y_AXES,
+};
 use bitflags::bitflags;
 pub use imgui::Condition;
 use implot_sys as sys;
@@ -662,6 +664,47 @@ impl PlotToken {
         self.context = std::ptr::null();
         unsafe { sys::ImPlot_EndPlot() };
     }
+
+    /// Get the position of the top left corner of the plot area in screen coordinates (pixels).
+    #[rustversion::attr(since(1.48), doc(alias = "GetPlotPos"))]
+    pub fn plot_pos(&self) -> ImVec2 {
+        let mut position = ImVec2 { x: 0.0, y: 0.0 }; // doesn't seem to have default()
+        unsafe {
+            sys::ImPlot_GetPlotPos(&mut position as *mut ImVec2);
+        }
+        position
+    }
+
+    /// Get the size of the plot area in pixels.
+    #[rustversion::attr(since(1.48), doc(alias = "GetPlotSize"))]
+    pub fn plot_size(&self) -> ImVec2 {
+        let mut size = ImVec2 { x: 0.0, y: 0.0 }; // doesn't seem to have default()
+        unsafe {
+            sys::ImPlot_GetPlotSize(&mut size as *mut ImVec2);
+        }
+        size
+    }
+
+    /// Get the draw list of this plot for drawing custom shapes in plot coordinates. Drawing is
+    /// clipped to the plot area until the returned draw list is dropped.
+    #[rustversion::attr(since(1.48), doc(alias = "GetPlotDrawList"))]
+    pub fn draw_list(&self) -> PlotDrawList<'_> {
+        PlotDrawList::new(self)
+    }
+
+    /// Get the screen rectangle of the plot area as its top left and bottom right corners, in
+    /// pixels. This is useful for placing other imgui widgets on top of the plot.
+    pub fn plot_rect(&self) -> (ImVec2, ImVec2) {
+        let position = self.plot_pos();
+        let size = self.plot_size();
+        (
+            position,
+            ImVec2 {
+                x: position.x + size.x,
+                y: position.y + size.y,
+            },
+        )
+    }
 }
 
 impl Drop for PlotToken {
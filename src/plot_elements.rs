@@ -3,17 +3,614 @@
 //! This module defines the various structs that can be used for drawing different things such
 //! as lines, bars, scatter plots and text in a plot. For the module to create plots themselves,
 //! see `plot`.
-use crate::sys;
+use crate::getter::PointGetter;
+use crate::strided::{shared_layout, shared_layout_of};
+use crate::{sys, Condition, Marker, Strided, IMPLOT_AUTO, IMPLOT_AUTO_COLOR};
 use std::ffi::CString;
 use std::os::raw::c_char;
 
 pub use crate::sys::ImPlotPoint;
 
+// --- Per-item styling --------------------------------------------------------------------------
+/// Internally-used struct for storing style (and visibility) settings that only apply to a single
+/// plot item. These are set with the `SetNext*Style` and `HideNextItem` functions right before the
+/// item is plotted, which means that unlike the style push/pop functions, nothing has to be removed
+/// from a stack afterwards.
+#[derive(Clone, Default)]
+struct ItemStyle {
+    /// Line color, if it should differ from the automatically chosen one
+    line_color: Option<sys::ImVec4>,
+    /// Line weight in pixels, if it should differ from the one in the current style
+    line_weight: Option<f32>,
+    /// Fill color and alpha modifier, if they should differ from the automatic ones
+    fill: Option<(sys::ImVec4, f32)>,
+    /// Marker type and size in pixels, if they should differ from the ones in the current style
+    marker: Option<(Marker, f32)>,
+    /// Error bar color, whisker width and whisker weight, if they should differ from the ones
+    /// in the current style
+    error_bar: Option<(sys::ImVec4, f32, f32)>,
+    /// Whether the item is hidden, and under which condition this is set
+    hidden: Option<(bool, Condition)>,
+}
+
+impl ItemStyle {
+    /// Set the styles for the next item. Things that were not specified are left at their
+    /// automatic values.
+    fn apply(&self) {
+        unsafe {
+            if self.line_color.is_some() || self.line_weight.is_some() {
+                sys::ImPlot_SetNextLineStyle(
+                    self.line_color.unwrap_or(IMPLOT_AUTO_COLOR),
+                    self.line_weight.unwrap_or(IMPLOT_AUTO as f32),
+                );
+            }
+            if let Some((color, alpha)) = self.fill {
+                sys::ImPlot_SetNextFillStyle(color, alpha);
+            }
+            if let Some((marker, size)) = self.marker {
+                sys::ImPlot_SetNextMarkerStyle(
+                    marker as sys::ImPlotMarker,
+                    size,
+                    IMPLOT_AUTO_COLOR,
+                    IMPLOT_AUTO as f32,
+                    IMPLOT_AUTO_COLOR,
+                );
+            }
+            if let Some((color, size, weight)) = self.error_bar {
+                sys::ImPlot_SetNextErrorBarStyle(color, size, weight);
+            }
+            if let Some((hidden, condition)) = self.hidden {
+                sys::ImPlot_HideNextItem(hidden, condition as sys::ImGuiCond);
+            }
+        }
+    }
+}
+
+/// Information about a plot item that was just plotted, returned by the `plot` functions of the
+/// plot items.
+#[derive(Copy, Clone, Debug)]
+pub struct PlotItemResult {
+    /// Color of the item, if it was plotted
+    color: Option<sys::ImVec4>,
+}
+
+impl PlotItemResult {
+    /// Result for an item that was not plotted, for example because there was no data.
+    pub(crate) fn not_plotted() -> Self {
+        Self { color: None }
+    }
+
+    /// Result for the item that was plotted last.
+    fn from_last_item() -> Self {
+        Self {
+            color: Some(crate::last_item_color()),
+        }
+    }
+
+    /// Returns the color of the item, which is the automatically assigned color from the
+    /// current colormap unless a color was set explicitly. This can be used to match the colors
+    /// of other widgets to the item. For pie charts, this is the color of the last slice. Returns
+    /// `None` if nothing was plotted, for example because the data was empty.
+    #[rustversion::attr(since(1.48), doc(alias = "GetLastItemColor"))]
+    pub fn color(&self) -> Option<sys::ImVec4> {
+        self.color
+    }
+}
+
+// Implements the builder methods for per-item settings on the given plot item structs, all of
+// which are expected to have a `style: ItemStyle` field.
+macro_rules! impl_item_style_builders {
+    ($($item:ident),*) => {
+        $(
+            impl $item {
+                /// Set the line color for this item only. The components should be between 0.0
+                /// (no intensity) and 1.0 (full intensity).
+                #[rustversion::attr(since(1.48), doc(alias = "SetNextLineStyle"))]
+                pub fn with_line_color(mut self, color: sys::ImVec4) -> Self {
+                    self.style.line_color = Some(color);
+                    self
+                }
+
+                /// Set the line weight in pixels for this item only.
+                #[rustversion::attr(since(1.48), doc(alias = "SetNextLineStyle"))]
+                pub fn with_line_weight(mut self, weight: f32) -> Self {
+                    self.style.line_weight = Some(weight);
+                    self
+                }
+
+                /// Set the marker and its size in pixels for this item only.
+                #[rustversion::attr(since(1.48), doc(alias = "SetNextMarkerStyle"))]
+                pub fn with_marker(mut self, marker: Marker, size: f32) -> Self {
+                    self.style.marker = Some((marker, size));
+                    self
+                }
+
+                /// Set the fill color and the alpha modifier applied to it for this item only.
+                #[rustversion::attr(since(1.48), doc(alias = "SetNextFillStyle"))]
+                pub fn with_fill(mut self, color: sys::ImVec4, alpha: f32) -> Self {
+                    self.style.fill = Some((color, alpha));
+                    self
+                }
+
+                /// Set the error bar color, whisker width and whisker weight (both in pixels)
+                /// for this item only.
+                #[rustversion::attr(since(1.48), doc(alias = "SetNextErrorBarStyle"))]
+                pub fn with_error_bar_style(
+                    mut self,
+                    color: sys::ImVec4,
+                    size: f32,
+                    weight: f32,
+                ) -> Self {
+                    self.style.error_bar = Some((color, size, weight));
+                    self
+                }
+
+                /// Set whether this item is hidden, as if it was toggled off in the legend. With
+                /// `Condition::Once` or `Condition::FirstUseEver`, this only sets the initial
+                /// state and users can still toggle the item through the legend afterwards. With
+                /// `Condition::Always`, the visibility is forced every frame.
+                #[rustversion::attr(since(1.48), doc(alias = "HideNextItem"))]
+                pub fn hidden(mut self, hidden: bool, condition: Condition) -> Self {
+                    self.style.hidden = Some((hidden, condition));
+                    self
+                }
+            }
+        )*
+    };
+}
+
+// Text and annotations are not plot items in ImPlot, the styles would carry over to the
+// next item instead, so they are left out here.
+impl_item_style_builders!(
+    PlotLine,
+    PlotStairs,
+    PlotScatter,
+    PlotBars,
+    PlotHeatmap,
+    PlotStems,
+    PlotShaded,
+    PlotErrorBars,
+    PlotPieChart,
+    PlotDigital,
+    PlotImage
+);
+
+/// Numeric types that plot items can be created from directly, without converting the data to
+/// `f64` first. This is implemented for `f32`, `f64` and the 8, 16, 32 and 64 bit signed and
+/// unsigned integers, and cannot be implemented outside of this crate.
+pub trait PlotData: private::Sealed + Copy {
+    /// Convert a value to `f64`, for computations on the Rust side such as scale ranges.
+    #[doc(hidden)]
+    fn to_f64(self) -> f64;
+
+    /// Call the `PlotLine` variant of the C++ API that matches this type.
+    #[doc(hidden)]
+    unsafe fn plot_line(
+        label: *const c_char,
+        xs: *const Self,
+        ys: *const Self,
+        count: i32,
+        offset: i32,
+        stride: i32,
+    );
+
+    /// Call the `PlotScatter` variant of the C++ API that matches this type.
+    #[doc(hidden)]
+    unsafe fn plot_scatter(
+        label: *const c_char,
+        xs: *const Self,
+        ys: *const Self,
+        count: i32,
+        offset: i32,
+        stride: i32,
+    );
+
+    /// Call the `PlotStairs` variant of the C++ API that matches this type.
+    #[doc(hidden)]
+    unsafe fn plot_stairs(
+        label: *const c_char,
+        xs: *const Self,
+        ys: *const Self,
+        count: i32,
+        offset: i32,
+        stride: i32,
+    );
+
+    /// Call the `PlotBars` variant of the C++ API that matches this type.
+    #[doc(hidden)]
+    unsafe fn plot_bars(
+        label: *const c_char,
+        xs: *const Self,
+        ys: *const Self,
+        count: i32,
+        width: f64,
+        offset: i32,
+        stride: i32,
+    );
+
+    /// Call the `PlotBarsH` variant of the C++ API that matches this type.
+    #[doc(hidden)]
+    unsafe fn plot_bars_h(
+        label: *const c_char,
+        xs: *const Self,
+        ys: *const Self,
+        count: i32,
+        height: f64,
+        offset: i32,
+        stride: i32,
+    );
+
+    /// Call the `PlotStems` variant of the C++ API that matches this type.
+    #[doc(hidden)]
+    unsafe fn plot_stems(
+        label: *const c_char,
+        xs: *const Self,
+        ys: *const Self,
+        count: i32,
+        y_ref: f64,
+        offset: i32,
+        stride: i32,
+    );
+
+    /// Call the `PlotLine` variant of the C++ API for y values only that matches this type.
+    #[doc(hidden)]
+    unsafe fn plot_line_y(
+        label: *const c_char,
+        values: *const Self,
+        count: i32,
+        xscale: f64,
+        x0: f64,
+        offset: i32,
+        stride: i32,
+    );
+
+    /// Call the `PlotScatter` variant of the C++ API for y values only that matches this type.
+    #[doc(hidden)]
+    unsafe fn plot_scatter_y(
+        label: *const c_char,
+        values: *const Self,
+        count: i32,
+        xscale: f64,
+        x0: f64,
+        offset: i32,
+        stride: i32,
+    );
+
+    /// Call the `PlotStairs` variant of the C++ API for y values only that matches this type.
+    #[doc(hidden)]
+    unsafe fn plot_stairs_y(
+        label: *const c_char,
+        values: *const Self,
+        count: i32,
+        xscale: f64,
+        x0: f64,
+        offset: i32,
+        stride: i32,
+    );
+
+    /// Call the `PlotBars` variant of the C++ API for bar values only that matches this type.
+    #[doc(hidden)]
+    unsafe fn plot_bars_y(
+        label: *const c_char,
+        values: *const Self,
+        count: i32,
+        width: f64,
+        shift: f64,
+        offset: i32,
+        stride: i32,
+    );
+
+    /// Call the `PlotBarsH` variant of the C++ API for bar values only that matches this type.
+    #[doc(hidden)]
+    unsafe fn plot_bars_h_y(
+        label: *const c_char,
+        values: *const Self,
+        count: i32,
+        height: f64,
+        shift: f64,
+        offset: i32,
+        stride: i32,
+    );
+
+    /// Call the `PlotStems` variant of the C++ API for y values only that matches this type.
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn plot_stems_y(
+        label: *const c_char,
+        values: *const Self,
+        count: i32,
+        y_ref: f64,
+        xscale: f64,
+        x0: f64,
+        offset: i32,
+        stride: i32,
+    );
+
+    /// Call the `PlotShaded` variant of the C++ API for y values only that matches this type.
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn plot_shaded_y(
+        label: *const c_char,
+        values: *const Self,
+        count: i32,
+        y_ref: f64,
+        xscale: f64,
+        x0: f64,
+        offset: i32,
+        stride: i32,
+    );
+
+    /// Call the `PlotHeatmap` variant of the C++ API that matches this type.
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn plot_heatmap(
+        label: *const c_char,
+        values: *const Self,
+        rows: i32,
+        cols: i32,
+        scale_min: f64,
+        scale_max: f64,
+        label_format: *const c_char,
+        bounds_min: ImPlotPoint,
+        bounds_max: ImPlotPoint,
+    );
+}
+
+// Implements PlotData for a type, given the names of the C++ API functions for it
+macro_rules! impl_plot_data {
+    ($($type:ty => $line:ident, $scatter:ident, $stairs:ident, $bars:ident, $bars_h:ident,
+        $stems:ident, $heatmap:ident, $line_y:ident, $scatter_y:ident, $stairs_y:ident,
+        $bars_y:ident, $bars_h_y:ident, $stems_y:ident, $shaded_y:ident;)*) => {
+        $(
+            impl PlotData for $type {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                unsafe fn plot_line(
+                    label: *const c_char,
+                    xs: *const Self,
+                    ys: *const Self,
+                    count: i32,
+                    offset: i32,
+                    stride: i32,
+                ) {
+                    sys::$line(label, xs, ys, count, offset, stride);
+                }
+
+                unsafe fn plot_scatter(
+                    label: *const c_char,
+                    xs: *const Self,
+                    ys: *const Self,
+                    count: i32,
+                    offset: i32,
+                    stride: i32,
+                ) {
+                    sys::$scatter(label, xs, ys, count, offset, stride);
+                }
+
+                unsafe fn plot_stairs(
+                    label: *const c_char,
+                    xs: *const Self,
+                    ys: *const Self,
+                    count: i32,
+                    offset: i32,
+                    stride: i32,
+                ) {
+                    sys::$stairs(label, xs, ys, count, offset, stride);
+                }
+
+                unsafe fn plot_bars(
+                    label: *const c_char,
+                    xs: *const Self,
+                    ys: *const Self,
+                    count: i32,
+                    width: f64,
+                    offset: i32,
+                    stride: i32,
+                ) {
+                    sys::$bars(label, xs, ys, count, width, offset, stride);
+                }
+
+                unsafe fn plot_bars_h(
+                    label: *const c_char,
+                    xs: *const Self,
+                    ys: *const Self,
+                    count: i32,
+                    height: f64,
+                    offset: i32,
+                    stride: i32,
+                ) {
+                    sys::$bars_h(label, xs, ys, count, height, offset, stride);
+                }
+
+                unsafe fn plot_stems(
+                    label: *const c_char,
+                    xs: *const Self,
+                    ys: *const Self,
+                    count: i32,
+                    y_ref: f64,
+                    offset: i32,
+                    stride: i32,
+                ) {
+                    sys::$stems(label, xs, ys, count, y_ref, offset, stride);
+                }
+
+                unsafe fn plot_line_y(
+                    label: *const c_char,
+                    values: *const Self,
+                    count: i32,
+                    xscale: f64,
+                    x0: f64,
+                    offset: i32,
+                    stride: i32,
+                ) {
+                    sys::$line_y(label, values, count, xscale, x0, offset, stride);
+                }
+
+                unsafe fn plot_scatter_y(
+                    label: *const c_char,
+                    values: *const Self,
+                    count: i32,
+                    xscale: f64,
+                    x0: f64,
+                    offset: i32,
+                    stride: i32,
+                ) {
+                    sys::$scatter_y(label, values, count, xscale, x0, offset, stride);
+                }
+
+                unsafe fn plot_stairs_y(
+                    label: *const c_char,
+                    values: *const Self,
+                    count: i32,
+                    xscale: f64,
+                    x0: f64,
+                    offset: i32,
+                    stride: i32,
+                ) {
+                    sys::$stairs_y(label, values, count, xscale, x0, offset, stride);
+                }
+
+                unsafe fn plot_bars_y(
+                    label: *const c_char,
+                    values: *const Self,
+                    count: i32,
+                    width: f64,
+                    shift: f64,
+                    offset: i32,
+                    stride: i32,
+                ) {
+                    sys::$bars_y(label, values, count, width, shift, offset, stride);
+                }
+
+                unsafe fn plot_bars_h_y(
+                    label: *const c_char,
+                    values: *const Self,
+                    count: i32,
+                    height: f64,
+                    shift: f64,
+                    offset: i32,
+                    stride: i32,
+                ) {
+                    sys::$bars_h_y(label, values, count, height, shift, offset, stride);
+                }
+
+                unsafe fn plot_stems_y(
+                    label: *const c_char,
+                    values: *const Self,
+                    count: i32,
+                    y_ref: f64,
+                    xscale: f64,
+                    x0: f64,
+                    offset: i32,
+                    stride: i32,
+                ) {
+                    sys::$stems_y(label, values, count, y_ref, xscale, x0, offset, stride);
+                }
+
+                unsafe fn plot_shaded_y(
+                    label: *const c_char,
+                    values: *const Self,
+                    count: i32,
+                    y_ref: f64,
+                    xscale: f64,
+                    x0: f64,
+                    offset: i32,
+                    stride: i32,
+                ) {
+                    sys::$shaded_y(label, values, count, y_ref, xscale, x0, offset, stride);
+                }
+
+                unsafe fn plot_heatmap(
+                    label: *const c_char,
+                    values: *const Self,
+                    rows: i32,
+                    cols: i32,
+                    scale_min: f64,
+                    scale_max: f64,
+                    label_format: *const c_char,
+                    bounds_min: ImPlotPoint,
+                    bounds_max: ImPlotPoint,
+                ) {
+                    sys::$heatmap(
+                        label,
+                        values,
+                        rows,
+                        cols,
+                        scale_min,
+                        scale_max,
+                        label_format,
+                        bounds_min,
+                        bounds_max,
+                    );
+                }
+            }
+        )*
+    };
+}
+
+impl_plot_data! {
+    f32 => ImPlot_PlotLineFloatPtrFloatPtr, ImPlot_PlotScatterFloatPtrFloatPtr, ImPlot_PlotStairsFloatPtrFloatPtr,
+        ImPlot_PlotBarsFloatPtrFloatPtr, ImPlot_PlotBarsHFloatPtrFloatPtr, ImPlot_PlotStemsFloatPtrFloatPtr,
+        ImPlot_PlotHeatmapFloatPtr, ImPlot_PlotLineFloatPtrInt, ImPlot_PlotScatterFloatPtrInt,
+        ImPlot_PlotStairsFloatPtrInt, ImPlot_PlotBarsFloatPtrInt, ImPlot_PlotBarsHFloatPtrInt,
+        ImPlot_PlotStemsFloatPtrInt, ImPlot_PlotShadedFloatPtrInt;
+    f64 => ImPlot_PlotLinedoublePtrdoublePtr, ImPlot_PlotScatterdoublePtrdoublePtr, ImPlot_PlotStairsdoublePtrdoublePtr,
+        ImPlot_PlotBarsdoublePtrdoublePtr, ImPlot_PlotBarsHdoublePtrdoublePtr, ImPlot_PlotStemsdoublePtrdoublePtr,
+        ImPlot_PlotHeatmapdoublePtr, ImPlot_PlotLinedoublePtrInt, ImPlot_PlotScatterdoublePtrInt,
+        ImPlot_PlotStairsdoublePtrInt, ImPlot_PlotBarsdoublePtrInt, ImPlot_PlotBarsHdoublePtrInt,
+        ImPlot_PlotStemsdoublePtrInt, ImPlot_PlotShadeddoublePtrInt;
+    i8 => ImPlot_PlotLineS8PtrS8Ptr, ImPlot_PlotScatterS8PtrS8Ptr, ImPlot_PlotStairsS8PtrS8Ptr,
+        ImPlot_PlotBarsS8PtrS8Ptr, ImPlot_PlotBarsHS8PtrS8Ptr, ImPlot_PlotStemsS8PtrS8Ptr,
+        ImPlot_PlotHeatmapS8Ptr, ImPlot_PlotLineS8PtrInt, ImPlot_PlotScatterS8PtrInt,
+        ImPlot_PlotStairsS8PtrInt, ImPlot_PlotBarsS8PtrInt, ImPlot_PlotBarsHS8PtrInt,
+        ImPlot_PlotStemsS8PtrInt, ImPlot_PlotShadedS8PtrInt;
+    u8 => ImPlot_PlotLineU8PtrU8Ptr, ImPlot_PlotScatterU8PtrU8Ptr, ImPlot_PlotStairsU8PtrU8Ptr,
+        ImPlot_PlotBarsU8PtrU8Ptr, ImPlot_PlotBarsHU8PtrU8Ptr, ImPlot_PlotStemsU8PtrU8Ptr,
+        ImPlot_PlotHeatmapU8Ptr, ImPlot_PlotLineU8PtrInt, ImPlot_PlotScatterU8PtrInt,
+        ImPlot_PlotStairsU8PtrInt, ImPlot_PlotBarsU8PtrInt, ImPlot_PlotBarsHU8PtrInt,
+        ImPlot_PlotStemsU8PtrInt, ImPlot_PlotShadedU8PtrInt;
+    i16 => ImPlot_PlotLineS16PtrS16Ptr, ImPlot_PlotScatterS16PtrS16Ptr, ImPlot_PlotStairsS16PtrS16Ptr,
+        ImPlot_PlotBarsS16PtrS16Ptr, ImPlot_PlotBarsHS16PtrS16Ptr, ImPlot_PlotStemsS16PtrS16Ptr,
+        ImPlot_PlotHeatmapS16Ptr, ImPlot_PlotLineS16PtrInt, ImPlot_PlotScatterS16PtrInt,
+        ImPlot_PlotStairsS16PtrInt, ImPlot_PlotBarsS16PtrInt, ImPlot_PlotBarsHS16PtrInt,
+        ImPlot_PlotStemsS16PtrInt, ImPlot_PlotShadedS16PtrInt;
+    u16 => ImPlot_PlotLineU16PtrU16Ptr, ImPlot_PlotScatterU16PtrU16Ptr, ImPlot_PlotStairsU16PtrU16Ptr,
+        ImPlot_PlotBarsU16PtrU16Ptr, ImPlot_PlotBarsHU16PtrU16Ptr, ImPlot_PlotStemsU16PtrU16Ptr,
+        ImPlot_PlotHeatmapU16Ptr, ImPlot_PlotLineU16PtrInt, ImPlot_PlotScatterU16PtrInt,
+        ImPlot_PlotStairsU16PtrInt, ImPlot_PlotBarsU16PtrInt, ImPlot_PlotBarsHU16PtrInt,
+        ImPlot_PlotStemsU16PtrInt, ImPlot_PlotShadedU16PtrInt;
+    i32 => ImPlot_PlotLineS32PtrS32Ptr, ImPlot_PlotScatterS32PtrS32Ptr, ImPlot_PlotStairsS32PtrS32Ptr,
+        ImPlot_PlotBarsS32PtrS32Ptr, ImPlot_PlotBarsHS32PtrS32Ptr, ImPlot_PlotStemsS32PtrS32Ptr,
+        ImPlot_PlotHeatmapS32Ptr, ImPlot_PlotLineS32PtrInt, ImPlot_PlotScatterS32PtrInt,
+        ImPlot_PlotStairsS32PtrInt, ImPlot_PlotBarsS32PtrInt, ImPlot_PlotBarsHS32PtrInt,
+        ImPlot_PlotStemsS32PtrInt, ImPlot_PlotShadedS32PtrInt;
+    u32 => ImPlot_PlotLineU32PtrU32Ptr, ImPlot_PlotScatterU32PtrU32Ptr, ImPlot_PlotStairsU32PtrU32Ptr,
+        ImPlot_PlotBarsU32PtrU32Ptr, ImPlot_PlotBarsHU32PtrU32Ptr, ImPlot_PlotStemsU32PtrU32Ptr,
+        ImPlot_PlotHeatmapU32Ptr, ImPlot_PlotLineU32PtrInt, ImPlot_PlotScatterU32PtrInt,
+        ImPlot_PlotStairsU32PtrInt, ImPlot_PlotBarsU32PtrInt, ImPlot_PlotBarsHU32PtrInt,
+        ImPlot_PlotStemsU32PtrInt, ImPlot_PlotShadedU32PtrInt;
+    i64 => ImPlot_PlotLineS64PtrS64Ptr, ImPlot_PlotScatterS64PtrS64Ptr, ImPlot_PlotStairsS64PtrS64Ptr,
+        ImPlot_PlotBarsS64PtrS64Ptr, ImPlot_PlotBarsHS64PtrS64Ptr, ImPlot_PlotStemsS64PtrS64Ptr,
+        ImPlot_PlotHeatmapS64Ptr, ImPlot_PlotLineS64PtrInt, ImPlot_PlotScatterS64PtrInt,
+        ImPlot_PlotStairsS64PtrInt, ImPlot_PlotBarsS64PtrInt, ImPlot_PlotBarsHS64PtrInt,
+        ImPlot_PlotStemsS64PtrInt, ImPlot_PlotShadedS64PtrInt;
+    u64 => ImPlot_PlotLineU64PtrU64Ptr, ImPlot_PlotScatterU64PtrU64Ptr, ImPlot_PlotStairsU64PtrU64Ptr,
+        ImPlot_PlotBarsU64PtrU64Ptr, ImPlot_PlotBarsHU64PtrU64Ptr, ImPlot_PlotStemsU64PtrU64Ptr,
+        ImPlot_PlotHeatmapU64Ptr, ImPlot_PlotLineU64PtrInt, ImPlot_PlotScatterU64PtrInt,
+        ImPlot_PlotStairsU64PtrInt, ImPlot_PlotBarsU64PtrInt, ImPlot_PlotBarsHU64PtrInt,
+        ImPlot_PlotStemsU64PtrInt, ImPlot_PlotShadedU64PtrInt;
+}
+
 // --- Actual plotting functionality -------------------------------------------------------------
 /// Struct to provide functionality for plotting a line in a plot.
 pub struct PlotLine {
     /// Label to show in the legend for this line
     label: CString,
+
+    /// Style settings for this item only
+    style: ItemStyle,
 }
 
 impl PlotLine {
@@ -25,25 +622,91 @@ impl PlotLine {
         Self {
             label: CString::new(label)
                 .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label)),
+            style: ItemStyle::default(),
         }
     }
 
     /// Plot a line. Use this in closures passed to [`Plot::build()`](struct.Plot.html#method.build)
-    pub fn plot(&self, x: &[f64], y: &[f64]) {
+    pub fn plot<T: PlotData>(&self, x: &[T], y: &[T]) -> PlotItemResult {
+        self.plot_strided(Strided::new(x), Strided::new(y))
+    }
+
+    /// Like `plot`, but with the values read from strided views, for example from fields of a
+    /// slice of structs. The x and y values have to share the same stride and offset.
+    pub fn plot_strided<T: PlotData>(&self, x: Strided<T>, y: Strided<T>) -> PlotItemResult {
         // If there is no data to plot, we stop here
-        if x.len().min(y.len()) == 0 {
-            return;
-        }
+        let layout = match shared_layout(&x, &y) {
+            Some(layout) => layout,
+            None => return PlotItemResult::not_plotted(),
+        };
+        self.style.apply();
         unsafe {
-            sys::ImPlot_PlotLinedoublePtrdoublePtr(
+            T::plot_line(
                 self.label.as_ptr() as *const c_char,
                 x.as_ptr(),
                 y.as_ptr(),
-                x.len().min(y.len()) as i32, // "as" casts saturate as of Rust 1.45. This is safe here.
-                0,                           // No offset
-                std::mem::size_of::<f64>() as i32, // Stride, set to one f64 for the standard use case
+                layout.count,
+                layout.offset,
+                layout.stride,
+            );
+        }
+        PlotItemResult::from_last_item()
+    }
+
+    /// Like `plot`, but with the points computed by `getter` for every index in `0..count`, so
+    /// computed or transformed data can be plotted without collecting it into vectors first.
+    /// The closure may be called several times for the same index, and in any order.
+    #[rustversion::attr(since(1.48), doc(alias = "PlotLineG"))]
+    pub fn plot_fn<F: FnMut(usize) -> ImPlotPoint>(
+        &self,
+        count: usize,
+        getter: F,
+    ) -> PlotItemResult {
+        // If there is no data to plot, we stop here
+        if count == 0 {
+            return PlotItemResult::not_plotted();
+        }
+        self.style.apply();
+        let mut getter = PointGetter::new(getter);
+        unsafe {
+            sys::ImPlot_PlotLineG(
+                self.label.as_ptr() as *const c_char,
+                getter.pointer_function(),
+                getter.data(),
+                count as i32, // "as" casts saturate as of Rust 1.45. This is safe here.
+                0,            // No offset
+            );
+        }
+        getter.finish();
+        PlotItemResult::from_last_item()
+    }
+
+    /// Plot a line through the given y values, at x positions 0, 1, 2 and so on. Use this in
+    /// closures passed to [`Plot::build()`](struct.Plot.html#method.build)
+    pub fn plot_y<T: PlotData>(&self, values: &[T]) -> PlotItemResult {
+        self.plot_y_scaled(values, 0.0, 1.0)
+    }
+
+    /// Like `plot_y`, but with the x positions starting at `x0` and `dx` apart, as is useful
+    /// for uniformly sampled signals.
+    pub fn plot_y_scaled<T: PlotData>(&self, values: &[T], x0: f64, dx: f64) -> PlotItemResult {
+        // If there is no data to plot, we stop here
+        if values.is_empty() {
+            return PlotItemResult::not_plotted();
+        }
+        self.style.apply();
+        unsafe {
+            T::plot_line_y(
+                self.label.as_ptr() as *const c_char,
+                values.as_ptr(),
+                values.len() as i32, // "as" casts saturate as of Rust 1.45. This is safe here.
+                dx,
+                x0,
+                0,                               // No offset
+                std::mem::size_of::<T>() as i32, // Stride, set to one value for the standard use case
             );
         }
+        PlotItemResult::from_last_item()
     }
 }
 
@@ -51,6 +714,9 @@ impl PlotLine {
 pub struct PlotStairs {
     /// Label to show in the legend for this line
     label: CString,
+
+    /// Style settings for this item only
+    style: ItemStyle,
 }
 
 impl PlotStairs {
@@ -62,26 +728,91 @@ impl PlotStairs {
         Self {
             label: CString::new(label)
                 .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label)),
+            style: ItemStyle::default(),
         }
     }
 
     /// Plot a stairs style line. Use this in closures passed to
     /// [`Plot::build()`](struct.Plot.html#method.build)
-    pub fn plot(&self, x: &[f64], y: &[f64]) {
+    pub fn plot<T: PlotData>(&self, x: &[T], y: &[T]) -> PlotItemResult {
+        self.plot_strided(Strided::new(x), Strided::new(y))
+    }
+
+    /// Like `plot`, but with the values read from strided views, for example from fields of a
+    /// slice of structs. The x and y values have to share the same stride and offset.
+    pub fn plot_strided<T: PlotData>(&self, x: Strided<T>, y: Strided<T>) -> PlotItemResult {
         // If there is no data to plot, we stop here
-        if x.len().min(y.len()) == 0 {
-            return;
-        }
+        let layout = match shared_layout(&x, &y) {
+            Some(layout) => layout,
+            None => return PlotItemResult::not_plotted(),
+        };
+        self.style.apply();
         unsafe {
-            sys::ImPlot_PlotStairsdoublePtrdoublePtr(
+            T::plot_stairs(
                 self.label.as_ptr() as *const c_char,
                 x.as_ptr(),
                 y.as_ptr(),
-                x.len().min(y.len()) as i32, // "as" casts saturate as of Rust 1.45. This is safe here.
-                0,                           // No offset
-                std::mem::size_of::<f64>() as i32, // Stride, set to one f64 for the standard use case
+                layout.count,
+                layout.offset,
+                layout.stride,
             );
         }
+        PlotItemResult::from_last_item()
+    }
+
+    /// Like `plot`, but with the points computed by `getter` for every index in `0..count`.
+    /// The closure may be called several times for the same index, and in any order.
+    #[rustversion::attr(since(1.48), doc(alias = "PlotStairsG"))]
+    pub fn plot_fn<F: FnMut(usize) -> ImPlotPoint>(
+        &self,
+        count: usize,
+        getter: F,
+    ) -> PlotItemResult {
+        // If there is no data to plot, we stop here
+        if count == 0 {
+            return PlotItemResult::not_plotted();
+        }
+        self.style.apply();
+        let mut getter = PointGetter::new(getter);
+        unsafe {
+            sys::ImPlot_PlotStairsG(
+                self.label.as_ptr() as *const c_char,
+                getter.function(),
+                getter.data(),
+                count as i32, // "as" casts saturate as of Rust 1.45. This is safe here.
+                0,            // No offset
+            );
+        }
+        getter.finish();
+        PlotItemResult::from_last_item()
+    }
+
+    /// Plot a stairs style line through the given y values, at x positions 0, 1, 2 and so
+    /// on. Use this in closures passed to [`Plot::build()`](struct.Plot.html#method.build)
+    pub fn plot_y<T: PlotData>(&self, values: &[T]) -> PlotItemResult {
+        self.plot_y_scaled(values, 0.0, 1.0)
+    }
+
+    /// Like `plot_y`, but with the x positions starting at `x0` and `dx` apart, as is useful
+    /// for uniformly sampled signals.
+    pub fn plot_y_scaled<T: PlotData>(&self, values: &[T], x0: f64, dx: f64) -> PlotItemResult {
+        // If there is no data to plot, we stop here
+        if values.is_empty() {
+            return PlotItemResult::not_plotted();
+        }
+        self.style.apply();
+        unsafe {
+            T::plot_stairs_y(
+                self.label.as_ptr() as *const c_char,
+                values.as_ptr(),
+                values.len() as i32, // "as" casts saturate as of Rust 1.45. This is safe here.
+                dx,
+                x0,
+                0,                               // No offset
+                std::mem::size_of::<T>() as i32, // Stride, set to one value for the standard use case
+            );
+        }
+        PlotItemResult::from_last_item()
     }
 }
 
@@ -92,6 +823,9 @@ pub struct PlotScatter {
     /// # Panics
     /// Will panic if the label string contains internal null bytes.
     label: CString,
+
+    /// Style settings for this item only
+    style: ItemStyle,
 }
 
 impl PlotScatter {
@@ -100,26 +834,91 @@ impl PlotScatter {
         Self {
             label: CString::new(label)
                 .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label)),
+            style: ItemStyle::default(),
         }
     }
 
     /// Draw a previously-created scatter plot. Use this in closures passed to
     /// [`Plot::build()`](struct.Plot.html#method.build)
-    pub fn plot(&self, x: &[f64], y: &[f64]) {
+    pub fn plot<T: PlotData>(&self, x: &[T], y: &[T]) -> PlotItemResult {
+        self.plot_strided(Strided::new(x), Strided::new(y))
+    }
+
+    /// Like `plot`, but with the values read from strided views, for example from fields of a
+    /// slice of structs. The x and y values have to share the same stride and offset.
+    pub fn plot_strided<T: PlotData>(&self, x: Strided<T>, y: Strided<T>) -> PlotItemResult {
         // If there is no data to plot, we stop here
-        if x.len().min(y.len()) == 0 {
-            return;
-        }
+        let layout = match shared_layout(&x, &y) {
+            Some(layout) => layout,
+            None => return PlotItemResult::not_plotted(),
+        };
+        self.style.apply();
         unsafe {
-            sys::ImPlot_PlotScatterdoublePtrdoublePtr(
+            T::plot_scatter(
                 self.label.as_ptr() as *const c_char,
                 x.as_ptr(),
                 y.as_ptr(),
-                x.len().min(y.len()) as i32, // "as" casts saturate as of Rust 1.45. This is safe here.
-                0,                           // No offset
-                std::mem::size_of::<f64>() as i32, // Stride, set to one f64 for the standard use case
+                layout.count,
+                layout.offset,
+                layout.stride,
+            );
+        }
+        PlotItemResult::from_last_item()
+    }
+
+    /// Like `plot`, but with the points computed by `getter` for every index in `0..count`.
+    /// The closure may be called several times for the same index, and in any order.
+    #[rustversion::attr(since(1.48), doc(alias = "PlotScatterG"))]
+    pub fn plot_fn<F: FnMut(usize) -> ImPlotPoint>(
+        &self,
+        count: usize,
+        getter: F,
+    ) -> PlotItemResult {
+        // If there is no data to plot, we stop here
+        if count == 0 {
+            return PlotItemResult::not_plotted();
+        }
+        self.style.apply();
+        let mut getter = PointGetter::new(getter);
+        unsafe {
+            sys::ImPlot_PlotScatterG(
+                self.label.as_ptr() as *const c_char,
+                getter.pointer_function(),
+                getter.data(),
+                count as i32, // "as" casts saturate as of Rust 1.45. This is safe here.
+                0,            // No offset
+            );
+        }
+        getter.finish();
+        PlotItemResult::from_last_item()
+    }
+
+    /// Draw a scatter plot of the given y values, at x positions 0, 1, 2 and so on. Use
+    /// this in closures passed to [`Plot::build()`](struct.Plot.html#method.build)
+    pub fn plot_y<T: PlotData>(&self, values: &[T]) -> PlotItemResult {
+        self.plot_y_scaled(values, 0.0, 1.0)
+    }
+
+    /// Like `plot_y`, but with the x positions starting at `x0` and `dx` apart, as is useful
+    /// for uniformly sampled signals.
+    pub fn plot_y_scaled<T: PlotData>(&self, values: &[T], x0: f64, dx: f64) -> PlotItemResult {
+        // If there is no data to plot, we stop here
+        if values.is_empty() {
+            return PlotItemResult::not_plotted();
+        }
+        self.style.apply();
+        unsafe {
+            T::plot_scatter_y(
+                self.label.as_ptr() as *const c_char,
+                values.as_ptr(),
+                values.len() as i32, // "as" casts saturate as of Rust 1.45. This is safe here.
+                dx,
+                x0,
+                0,                               // No offset
+                std::mem::size_of::<T>() as i32, // Stride, set to one value for the standard use case
             );
         }
+        PlotItemResult::from_last_item()
     }
 }
 
@@ -133,6 +932,9 @@ pub struct PlotBars {
 
     /// Horizontal bar mode
     horizontal_bars: bool,
+
+    /// Style settings for this item only
+    style: ItemStyle,
 }
 
 impl PlotBars {
@@ -147,6 +949,7 @@ impl PlotBars {
                 .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label)),
             bar_width: 0.67, // Default value taken from C++ implot
             horizontal_bars: false,
+            style: ItemStyle::default(),
         }
     }
 
@@ -166,12 +969,24 @@ impl PlotBars {
     /// [`Plot::build()`](struct.Plot.html#method.build). The `axis_positions`
     /// specify where on the corresponding axis (X for vertical mode, Y for horizontal mode) the
     /// bar is drawn, and the `bar_values` specify what values the bars have.
-    pub fn plot(&self, axis_positions: &[f64], bar_values: &[f64]) {
-        let number_of_points = axis_positions.len().min(bar_values.len());
+    pub fn plot<T: PlotData>(&self, axis_positions: &[T], bar_values: &[T]) -> PlotItemResult {
+        self.plot_strided(Strided::new(axis_positions), Strided::new(bar_values))
+    }
+
+    /// Like `plot`, but with the values read from strided views, for example from fields of a
+    /// slice of structs. The axis positions and bar values have to share the same stride and
+    /// offset.
+    pub fn plot_strided<T: PlotData>(
+        &self,
+        axis_positions: Strided<T>,
+        bar_values: Strided<T>,
+    ) -> PlotItemResult {
         // If there is no data to plot, we stop here
-        if number_of_points == 0 {
-            return;
-        }
+        let layout = match shared_layout(&axis_positions, &bar_values) {
+            Some(layout) => layout,
+            None => return PlotItemResult::not_plotted(),
+        };
+        self.style.apply();
         unsafe {
             // C++ implot has separate functions for the two variants, but the interfaces
             // are the same, so they are unified here. The x and y values have different
@@ -179,29 +994,13 @@ impl PlotBars {
             // plotting function.
             let (plot_function, x, y);
             if self.horizontal_bars {
-                plot_function = sys::ImPlot_PlotBarsHdoublePtrdoublePtr
-                    as unsafe extern "C" fn(
-                        *const c_char,
-                        *const f64,
-                        *const f64,
-                        i32,
-                        f64,
-                        i32,
-                        i32,
-                    );
+                plot_function = T::plot_bars_h
+                    as unsafe fn(*const c_char, *const T, *const T, i32, f64, i32, i32);
                 x = bar_values;
                 y = axis_positions;
             } else {
-                plot_function = sys::ImPlot_PlotBarsdoublePtrdoublePtr
-                    as unsafe extern "C" fn(
-                        *const c_char,
-                        *const f64,
-                        *const f64,
-                        i32,
-                        f64,
-                        i32,
-                        i32,
-                    );
+                plot_function = T::plot_bars
+                    as unsafe fn(*const c_char, *const T, *const T, i32, f64, i32, i32);
                 x = axis_positions;
                 y = bar_values;
             };
@@ -210,12 +1009,110 @@ impl PlotBars {
                 self.label.as_ptr() as *const c_char,
                 x.as_ptr(),
                 y.as_ptr(),
-                number_of_points as i32, // "as" casts saturate as of Rust 1.45. This is safe here.
+                layout.count,
                 self.bar_width,
-                0,                                 // No offset
-                std::mem::size_of::<f64>() as i32, // Stride, set to one f64 for the standard use case
+                layout.offset,
+                layout.stride,
             );
         }
+        PlotItemResult::from_last_item()
+    }
+
+    /// Like `plot`, but with the bars computed by `getter` for every index in `0..count`. The
+    /// x coordinate of each point is the axis position and the y coordinate the bar value for
+    /// vertical bars, and the other way around for horizontal bars, as ImPlot expects them.
+    /// The closure may be called several times for the same index, and in any order.
+    #[rustversion::attr(since(1.48), doc(alias = "PlotBarsG"))]
+    #[rustversion::attr(since(1.48), doc(alias = "PlotBarsHG"))]
+    pub fn plot_fn<F: FnMut(usize) -> ImPlotPoint>(
+        &self,
+        count: usize,
+        getter: F,
+    ) -> PlotItemResult {
+        // If there is no data to plot, we stop here
+        if count == 0 {
+            return PlotItemResult::not_plotted();
+        }
+        self.style.apply();
+        let mut getter = PointGetter::new(getter);
+        unsafe {
+            // C++ implot has separate functions for the two variants, but the interfaces
+            // are the same, so they are unified here.
+            let plot_function = if self.horizontal_bars {
+                sys::ImPlot_PlotBarsHG
+            } else {
+                sys::ImPlot_PlotBarsG
+            };
+            plot_function(
+                self.label.as_ptr() as *const c_char,
+                getter.pointer_function(),
+                getter.data(),
+                count as i32, // "as" casts saturate as of Rust 1.45. This is safe here.
+                self.bar_width,
+                0, // No offset
+            );
+        }
+        getter.finish();
+        PlotItemResult::from_last_item()
+    }
+
+    /// Draw bars for the given bar values, at axis positions 0, 1, 2 and so on (on the X axis
+    /// for vertical mode, the Y axis for horizontal mode). Use this in closures passed to
+    /// [`Plot::build()`](struct.Plot.html#method.build)
+    pub fn plot_y<T: PlotData>(&self, bar_values: &[T]) -> PlotItemResult {
+        self.plot_y_shifted(bar_values, 0.0)
+    }
+
+    /// Like `plot_y`, but with the axis positions starting at `x0` and `dx` apart. C++ implot
+    /// can only shift the positions of bars given by their values alone, not scale them. So
+    /// while ImPlot reads the values directly if `dx` is 1, for other spacings the points are
+    /// passed through [`PlotBars::plot_fn`] instead, with each value converted to `f64` on the
+    /// Rust side.
+    pub fn plot_y_scaled<T: PlotData>(&self, bar_values: &[T], x0: f64, dx: f64) -> PlotItemResult {
+        if dx == 1.0 {
+            return self.plot_y_shifted(bar_values, x0);
+        }
+        self.plot_fn(bar_values.len(), |index| {
+            let position = x0 + dx * index as f64;
+            let value = bar_values[index].to_f64();
+            if self.horizontal_bars {
+                ImPlotPoint {
+                    x: value,
+                    y: position,
+                }
+            } else {
+                ImPlotPoint {
+                    x: position,
+                    y: value,
+                }
+            }
+        })
+    }
+
+    /// Draw bars for the given bar values, at axis positions `shift`, `shift + 1` and so on.
+    fn plot_y_shifted<T: PlotData>(&self, bar_values: &[T], shift: f64) -> PlotItemResult {
+        // If there is no data to plot, we stop here
+        if bar_values.is_empty() {
+            return PlotItemResult::not_plotted();
+        }
+        self.style.apply();
+        unsafe {
+            let plot_function = if self.horizontal_bars {
+                T::plot_bars_h_y as unsafe fn(*const c_char, *const T, i32, f64, f64, i32, i32)
+            } else {
+                T::plot_bars_y as unsafe fn(*const c_char, *const T, i32, f64, f64, i32, i32)
+            };
+            plot_function(
+                self.label.as_ptr() as *const c_char,
+                bar_values.as_ptr(),
+                bar_values.len() as i32, // "as" casts saturate as of Rust 1.45. This is safe here.
+                self.bar_width,
+                shift,
+                0,                               // No offset
+                std::mem::size_of::<T>() as i32, // Stride, set to one value for the standard use case
+            );
+        }
+        PlotItemResult::from_last_item()
     }
 }
 
@@ -298,6 +1195,9 @@ pub struct PlotHeatmap {
 
     /// Upper right point for the bounding rectangle. This is called `bounds_max` in the C++ code.
     drawarea_upper_right: ImPlotPoint,
+
+    /// Style settings for this item only
+    style: ItemStyle,
 }
 
 impl PlotHeatmap {
@@ -313,6 +1213,7 @@ impl PlotHeatmap {
             label_format: Some(CString::new("%.1f").unwrap()),
             drawarea_lower_left: ImPlotPoint { x: 0.0, y: 0.0 },
             drawarea_upper_right: ImPlotPoint { x: 1.0, y: 1.0 },
+            style: ItemStyle::default(),
         }
     }
 
@@ -351,20 +1252,26 @@ impl PlotHeatmap {
 
     /// Plot the heatmap, with the given values (assumed to be in row-major order),
     /// number of rows and number of columns.
-    pub fn plot(&self, values: &[f64], number_of_rows: u32, number_of_cols: u32) {
+    pub fn plot<T: PlotData>(
+        &self,
+        values: &[T],
+        number_of_rows: u32,
+        number_of_cols: u32,
+    ) -> PlotItemResult {
         // If no range was given, determine that range
         let scale_range = self.scale_range.unwrap_or_else(|| {
-            let mut min_seen = values[0];
-            let mut max_seen = values[0];
+            let mut min_seen = values[0].to_f64();
+            let mut max_seen = values[0].to_f64();
             values.iter().for_each(|value| {
-                min_seen = min_seen.min(*value);
-                max_seen = max_seen.max(*value);
+                min_seen = min_seen.min(value.to_f64());
+                max_seen = max_seen.max(value.to_f64());
             });
             (min_seen, max_seen)
         });
 
+        self.style.apply();
         unsafe {
-            sys::ImPlot_PlotHeatmapdoublePtr(
+            T::plot_heatmap(
                 self.label.as_ptr() as *const c_char,
                 values.as_ptr(),
                 number_of_rows as i32, // Not sure why C++ code uses a signed value here
@@ -382,6 +1289,7 @@ impl PlotHeatmap {
                 self.drawarea_upper_right,
             );
         }
+        PlotItemResult::from_last_item()
     }
 }
 
@@ -392,6 +1300,9 @@ pub struct PlotStems {
 
     /// Reference value for the y value, which the stems are "with respect to"
     reference_y: f64,
+
+    /// Style settings for this item only
+    style: ItemStyle,
 }
 
 impl PlotStems {
@@ -402,6 +1313,7 @@ impl PlotStems {
             label: CString::new(label)
                 .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label)),
             reference_y: 0.0, // Default value taken from C++ implot
+            style: ItemStyle::default(),
         }
     }
 
@@ -414,22 +1326,892 @@ impl PlotStems {
     /// Draw a previously-created stem plot. Use this in closures passed to
     /// [`Plot::build()`](struct.Plot.html#method.build). The `axis_positions` specify where on the
     /// X axis the stems are drawn, and the `stem_values` specify what values the stems have.
-    pub fn plot(&self, axis_positions: &[f64], stem_values: &[f64]) {
-        let number_of_points = axis_positions.len().min(stem_values.len());
+    pub fn plot<T: PlotData>(&self, axis_positions: &[T], stem_values: &[T]) -> PlotItemResult {
+        self.plot_strided(Strided::new(axis_positions), Strided::new(stem_values))
+    }
+
+    /// Like `plot`, but with the values read from strided views, for example from fields of a
+    /// slice of structs. The axis positions and stem values have to share the same stride and
+    /// offset.
+    pub fn plot_strided<T: PlotData>(
+        &self,
+        axis_positions: Strided<T>,
+        stem_values: Strided<T>,
+    ) -> PlotItemResult {
         // If there is no data to plot, we stop here
-        if number_of_points == 0 {
-            return;
-        }
+        let layout = match shared_layout(&axis_positions, &stem_values) {
+            Some(layout) => layout,
+            None => return PlotItemResult::not_plotted(),
+        };
+        self.style.apply();
         unsafe {
-            sys::ImPlot_PlotStemsdoublePtrdoublePtr(
+            T::plot_stems(
                 self.label.as_ptr() as *const c_char,
                 axis_positions.as_ptr(),
                 stem_values.as_ptr(),
-                number_of_points as i32, // "as" casts saturate as of Rust 1.45. This is safe here.
+                layout.count,
+                self.reference_y,
+                layout.offset,
+                layout.stride,
+            );
+        }
+        PlotItemResult::from_last_item()
+    }
+
+    /// Draw stems for the given y values, at x positions 0, 1, 2 and so on. Use this in
+    /// closures passed to [`Plot::build()`](struct.Plot.html#method.build)
+    pub fn plot_y<T: PlotData>(&self, values: &[T]) -> PlotItemResult {
+        self.plot_y_scaled(values, 0.0, 1.0)
+    }
+
+    /// Like `plot_y`, but with the x positions starting at `x0` and `dx` apart, as is useful
+    /// for uniformly sampled signals.
+    pub fn plot_y_scaled<T: PlotData>(&self, values: &[T], x0: f64, dx: f64) -> PlotItemResult {
+        // If there is no data to plot, we stop here
+        if values.is_empty() {
+            return PlotItemResult::not_plotted();
+        }
+        self.style.apply();
+        unsafe {
+            T::plot_stems_y(
+                self.label.as_ptr() as *const c_char,
+                values.as_ptr(),
+                values.len() as i32, // "as" casts saturate as of Rust 1.45. This is safe here.
                 self.reference_y,
-                0,                                 // No offset
-                std::mem::size_of::<f64>() as i32, // Stride, set to one f64 for the standard use case
+                dx,
+                x0,
+                0,                               // No offset
+                std::mem::size_of::<T>() as i32, // Stride, set to one value for the standard use case
+            );
+        }
+        PlotItemResult::from_last_item()
+    }
+}
+
+/// Struct to provide functionality for plotting shaded regions, either between a line and a
+/// constant reference value or between two lines.
+pub struct PlotShaded {
+    /// Label to show in the legend for this shaded area
+    label: CString,
+
+    /// Reference value for the y value that the area is filled towards, used for the
+    /// single-line variant of the shaded plot.
+    reference_y: f64,
+
+    /// Style settings for this item only
+    style: ItemStyle,
+}
+
+impl PlotShaded {
+    /// Create a new shaded area to be plotted. Does not draw anything yet.
+    ///
+    /// # Panics
+    /// Will panic if the label string contains internal null bytes.
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: CString::new(label)
+                .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label)),
+            reference_y: 0.0, // Default value taken from C++ implot
+            style: ItemStyle::default(),
+        }
+    }
+
+    /// Set the reference y value to fill towards when using [`PlotShaded::plot`],
+    /// [`PlotShaded::plot_y`] or [`PlotShaded::plot_y_scaled`]. Has no effect on
+    /// [`PlotShaded::plot_between`].
+    pub fn with_reference_y(mut self, reference_y: f64) -> Self {
+        self.reference_y = reference_y;
+        self
+    }
+
+    /// Plot the area between the line given by `x` and `y` and the reference y value. Use this
+    /// in closures passed to [`Plot::build()`](struct.Plot.html#method.build)
+    #[rustversion::attr(since(1.48), doc(alias = "PlotShaded"))]
+    pub fn plot(&self, x: &[f64], y: &[f64]) -> PlotItemResult {
+        self.plot_strided(Strided::new(x), Strided::new(y))
+    }
+
+    /// Like `plot`, but with the values read from strided views, for example from fields of a
+    /// slice of structs. The x and y values have to share the same stride and offset.
+    #[rustversion::attr(since(1.48), doc(alias = "PlotShaded"))]
+    pub fn plot_strided(&self, x: Strided<f64>, y: Strided<f64>) -> PlotItemResult {
+        // If there is no data to plot, we stop here
+        let layout = match shared_layout(&x, &y) {
+            Some(layout) => layout,
+            None => return PlotItemResult::not_plotted(),
+        };
+        self.style.apply();
+        unsafe {
+            sys::ImPlot_PlotShadeddoublePtrdoublePtrInt(
+                self.label.as_ptr() as *const c_char,
+                x.as_ptr(),
+                y.as_ptr(),
+                layout.count,
+                self.reference_y,
+                layout.offset,
+                layout.stride,
+            );
+        }
+        PlotItemResult::from_last_item()
+    }
+
+    /// Plot the area between the two lines given by `x` and `y1` as well as `x` and `y2`. Use
+    /// this in closures passed to [`Plot::build()`](struct.Plot.html#method.build)
+    #[rustversion::attr(since(1.48), doc(alias = "PlotShaded"))]
+    pub fn plot_between(&self, x: &[f64], y1: &[f64], y2: &[f64]) -> PlotItemResult {
+        self.plot_between_strided(Strided::new(x), Strided::new(y1), Strided::new(y2))
+    }
+
+    /// Like `plot_between`, but with the values read from strided views. All three views have
+    /// to share the same stride and offset.
+    #[rustversion::attr(since(1.48), doc(alias = "PlotShaded"))]
+    pub fn plot_between_strided(
+        &self,
+        x: Strided<f64>,
+        y1: Strided<f64>,
+        y2: Strided<f64>,
+    ) -> PlotItemResult {
+        // If there is no data to plot, we stop here
+        let layout = match shared_layout_of(&[&x, &y1, &y2]) {
+            Some(layout) => layout,
+            None => return PlotItemResult::not_plotted(),
+        };
+        self.style.apply();
+        unsafe {
+            sys::ImPlot_PlotShadeddoublePtrdoublePtrdoublePtr(
+                self.label.as_ptr() as *const c_char,
+                x.as_ptr(),
+                y1.as_ptr(),
+                y2.as_ptr(),
+                layout.count,
+                layout.offset,
+                layout.stride,
             );
         }
+        PlotItemResult::from_last_item()
     }
+
+    /// Like `plot_between`, but with the points of the two lines computed by `getter1` and
+    /// `getter2` for every index in `0..count`. The closures may be called several times for the
+    /// same index, and in any order.
+    #[rustversion::attr(since(1.48), doc(alias = "PlotShadedG"))]
+    pub fn plot_between_fn<F1, F2>(&self, count: usize, getter1: F1, getter2: F2) -> PlotItemResult
+    where
+        F1: FnMut(usize) -> ImPlotPoint,
+        F2: FnMut(usize) -> ImPlotPoint,
+    {
+        // If there is no data to plot, we stop here
+        if count == 0 {
+            return PlotItemResult::not_plotted();
+        }
+        self.style.apply();
+        let mut getter1 = PointGetter::new(getter1);
+        let mut getter2 = PointGetter::new(getter2);
+        unsafe {
+            sys::ImPlot_PlotShadedG(
+                self.label.as_ptr() as *const c_char,
+                getter1.pointer_function(),
+                getter1.data(),
+                getter2.pointer_function(),
+                getter2.data(),
+                count as i32, // "as" casts saturate as of Rust 1.45. This is safe here.
+                0,            // No offset
+            );
+        }
+        getter1.finish();
+        getter2.finish();
+        PlotItemResult::from_last_item()
+    }
+
+    /// Plot the area between the line through the given y values, at x positions 0, 1, 2 and
+    /// so on, and the reference y value. Use this in
+    /// closures passed to [`Plot::build()`](struct.Plot.html#method.build)
+    pub fn plot_y<T: PlotData>(&self, values: &[T]) -> PlotItemResult {
+        self.plot_y_scaled(values, 0.0, 1.0)
+    }
+
+    /// Like `plot_y`, but with the x positions starting at `x0` and `dx` apart, as is useful
+    /// for uniformly sampled signals.
+    pub fn plot_y_scaled<T: PlotData>(&self, values: &[T], x0: f64, dx: f64) -> PlotItemResult {
+        // If there is no data to plot, we stop here
+        if values.is_empty() {
+            return PlotItemResult::not_plotted();
+        }
+        self.style.apply();
+        unsafe {
+            T::plot_shaded_y(
+                self.label.as_ptr() as *const c_char,
+                values.as_ptr(),
+                values.len() as i32, // "as" casts saturate as of Rust 1.45. This is safe here.
+                self.reference_y,
+                dx,
+                x0,
+                0,                               // No offset
+                std::mem::size_of::<T>() as i32, // Stride, set to one value for the standard use case
+            );
+        }
+        PlotItemResult::from_last_item()
+    }
+}
+
+/// Struct to provide error bar plotting functionality.
+pub struct PlotErrorBars {
+    /// Label to show in the legend for these error bars
+    label: CString,
+
+    /// Horizontal error bar mode
+    horizontal_bars: bool,
+
+    /// Style settings for this item only
+    style: ItemStyle,
+}
+
+impl PlotErrorBars {
+    /// Create new error bars to be shown. Defaults to drawing vertical error bars.
+    /// Does not draw anything yet.
+    ///
+    /// # Panics
+    /// Will panic if the label string contains internal null bytes.
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: CString::new(label)
+                .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label)),
+            horizontal_bars: false,
+            style: ItemStyle::default(),
+        }
+    }
+
+    /// Set the error bars to be horizontal (default is vertical)
+    pub fn with_horizontal_bars(mut self) -> Self {
+        self.horizontal_bars = true;
+        self
+    }
+
+    /// Draw symmetric error bars. Use this in closures passed to
+    /// [`Plot::build()`](struct.Plot.html#method.build). The `x` and `y` values specify the
+    /// centers of the error bars, and `error` specifies how far they extend in either direction
+    /// (along Y for vertical mode, along X for horizontal mode).
+    ///
+    /// # Panics
+    /// Will panic if the slices do not all have the same length.
+    #[rustversion::attr(since(1.48), doc(alias = "PlotErrorBars"))]
+    #[rustversion::attr(since(1.48), doc(alias = "PlotErrorBarsH"))]
+    pub fn plot(&self, x: &[f64], y: &[f64], error: &[f64]) -> PlotItemResult {
+        self.plot_strided(Strided::new(x), Strided::new(y), Strided::new(error))
+    }
+
+    /// Like `plot`, but with the values read from strided views, for example from fields of a
+    /// slice of structs. All three views have to share the same stride and offset.
+    ///
+    /// # Panics
+    /// Will panic if the views do not all have the same length, stride and offset.
+    #[rustversion::attr(since(1.48), doc(alias = "PlotErrorBars"))]
+    #[rustversion::attr(since(1.48), doc(alias = "PlotErrorBarsH"))]
+    pub fn plot_strided(
+        &self,
+        x: Strided<f64>,
+        y: Strided<f64>,
+        error: Strided<f64>,
+    ) -> PlotItemResult {
+        assert!(
+            x.len() == y.len() && x.len() == error.len(),
+            "Error bar slices for \"{:?}\" have differing lengths: x: {}, y: {}, error: {}",
+            self.label,
+            x.len(),
+            y.len(),
+            error.len()
+        );
+        // If there is no data to plot, we stop here
+        let layout = match shared_layout_of(&[&x, &y, &error]) {
+            Some(layout) => layout,
+            None => return PlotItemResult::not_plotted(),
+        };
+        self.style.apply();
+        unsafe {
+            // C++ implot has separate functions for the two variants, but the interfaces
+            // are the same, so they are unified here.
+            let plot_function = if self.horizontal_bars {
+                sys::ImPlot_PlotErrorBarsHdoublePtrdoublePtrdoublePtrInt
+                    as unsafe extern "C" fn(
+                        *const c_char,
+                        *const f64,
+                        *const f64,
+                        *const f64,
+                        i32,
+                        i32,
+                        i32,
+                    )
+            } else {
+                sys::ImPlot_PlotErrorBarsdoublePtrdoublePtrdoublePtrInt
+            };
+
+            plot_function(
+                self.label.as_ptr() as *const c_char,
+                x.as_ptr(),
+                y.as_ptr(),
+                error.as_ptr(),
+                layout.count,
+                layout.offset,
+                layout.stride,
+            );
+        }
+        PlotItemResult::from_last_item()
+    }
+
+    /// Draw asymmetric error bars. Use this in closures passed to
+    /// [`Plot::build()`](struct.Plot.html#method.build). The `x` and `y` values specify the
+    /// centers of the error bars, `negative_error` specifies how far they extend towards lower
+    /// values and `positive_error` how far they extend towards higher values (along Y for
+    /// vertical mode, along X for horizontal mode).
+    ///
+    /// # Panics
+    /// Will panic if the slices do not all have the same length.
+    #[rustversion::attr(since(1.48), doc(alias = "PlotErrorBars"))]
+    #[rustversion::attr(since(1.48), doc(alias = "PlotErrorBarsH"))]
+    pub fn plot_asymmetric(
+        &self,
+        x: &[f64],
+        y: &[f64],
+        negative_error: &[f64],
+        positive_error: &[f64],
+    ) -> PlotItemResult {
+        self.plot_asymmetric_strided(
+            Strided::new(x),
+            Strided::new(y),
+            Strided::new(negative_error),
+            Strided::new(positive_error),
+        )
+    }
+
+    /// Like `plot_asymmetric`, but with the values read from strided views. All four views have
+    /// to share the same stride and offset.
+    ///
+    /// # Panics
+    /// Will panic if the views do not all have the same length, stride and offset.
+    #[rustversion::attr(since(1.48), doc(alias = "PlotErrorBars"))]
+    #[rustversion::attr(since(1.48), doc(alias = "PlotErrorBarsH"))]
+    pub fn plot_asymmetric_strided(
+        &self,
+        x: Strided<f64>,
+        y: Strided<f64>,
+        negative_error: Strided<f64>,
+        positive_error: Strided<f64>,
+    ) -> PlotItemResult {
+        assert!(
+            x.len() == y.len()
+                && x.len() == negative_error.len()
+                && x.len() == positive_error.len(),
+            "Error bar slices for \"{:?}\" have differing lengths: x: {}, y: {}, negative error: \
+             {}, positive error: {}",
+            self.label,
+            x.len(),
+            y.len(),
+            negative_error.len(),
+            positive_error.len()
+        );
+        // If there is no data to plot, we stop here
+        let layout = match shared_layout_of(&[&x, &y, &negative_error, &positive_error]) {
+            Some(layout) => layout,
+            None => return PlotItemResult::not_plotted(),
+        };
+        self.style.apply();
+        unsafe {
+            let plot_function = if self.horizontal_bars {
+                sys::ImPlot_PlotErrorBarsHdoublePtrdoublePtrdoublePtrdoublePtr
+                    as unsafe extern "C" fn(
+                        *const c_char,
+                        *const f64,
+                        *const f64,
+                        *const f64,
+                        *const f64,
+                        i32,
+                        i32,
+                        i32,
+                    )
+            } else {
+                sys::ImPlot_PlotErrorBarsdoublePtrdoublePtrdoublePtrdoublePtr
+            };
+
+            plot_function(
+                self.label.as_ptr() as *const c_char,
+                x.as_ptr(),
+                y.as_ptr(),
+                negative_error.as_ptr(),
+                positive_error.as_ptr(),
+                layout.count,
+                layout.offset,
+                layout.stride,
+            );
+        }
+        PlotItemResult::from_last_item()
+    }
+}
+
+/// Struct to provide pie chart plotting functionality.
+pub struct PlotPieChart {
+    /// Labels of the individual slices, shown in the legend. Stored as CStrings because that is
+    /// what the C++ code needs, see also the comment on tick labels in `Plot`.
+    slice_labels: Vec<CString>,
+
+    /// Center of the pie chart, in plot coordinates
+    center: ImPlotPoint,
+
+    /// Radius of the pie chart, in plot coordinates
+    radius: f64,
+
+    /// Whether to normalize the values such that they always fill a full circle
+    normalize: bool,
+
+    /// Label format for the values shown on the slices. This is always generated from
+    /// [`PlotPieChart::with_value_labels`] so it cannot contain arbitrary format strings.
+    /// None means don't show values on the slices.
+    label_format: Option<CString>,
+
+    /// Angle in degrees at which the first slice starts
+    start_angle: f64,
+
+    /// Style settings for this item only
+    style: ItemStyle,
+}
+
+impl PlotPieChart {
+    /// Create a new pie chart to be shown, with one label per slice. Defaults to a chart
+    /// centered at `(0.5, 0.5)` with a radius of `0.4`, with values shown with four significant
+    /// digits. Does not draw anything yet.
+    ///
+    /// # Panics
+    /// Will panic if any of the label strings contain internal null bytes.
+    pub fn new(slice_labels: &[&str]) -> Self {
+        Self {
+            slice_labels: slice_labels
+                .iter()
+                .map(|label| {
+                    CString::new(*label).unwrap_or_else(|_| {
+                        panic!("Label string has internal null bytes: {}", label)
+                    })
+                })
+                .collect(),
+            center: ImPlotPoint { x: 0.5, y: 0.5 },
+            radius: 0.4,
+            normalize: false, // Default value taken from C++ implot
+            label_format: Some(pie_chart_label_format(4)),
+            start_angle: 90.0, // Default value taken from C++ implot
+            style: ItemStyle::default(),
+        }
+    }
+
+    /// Set the center of the pie chart, in plot coordinates
+    pub fn with_center(mut self, center: ImPlotPoint) -> Self {
+        self.center = center;
+        self
+    }
+
+    /// Set the radius of the pie chart, in plot coordinates
+    pub fn with_radius(mut self, radius: f64) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Normalize the values such that the slices always form a full circle. Without this, a full
+    /// circle is only drawn if the values sum up to 1, and values summing up to less than 1 lead
+    /// to a partial circle.
+    pub fn with_normalization(mut self, normalize: bool) -> Self {
+        self.normalize = normalize;
+        self
+    }
+
+    /// Set the angle (in degrees, counter-clockwise from the positive X axis) at which the
+    /// first slice starts.
+    pub fn with_start_angle(mut self, start_angle: f64) -> Self {
+        self.start_angle = start_angle;
+        self
+    }
+
+    /// Specify how many significant digits the values shown on the slices have. `None` means
+    /// no values are shown. The number of digits is clamped to between 1 and 15.
+    pub fn with_value_labels(mut self, significant_digits: Option<u8>) -> Self {
+        self.label_format = significant_digits.map(pie_chart_label_format);
+        self
+    }
+
+    /// Draw the pie chart with the given values, one per slice. Use this in closures passed to
+    /// [`Plot::build()`](struct.Plot.html#method.build). If the number of values and labels
+    /// differs, only as many slices as there are both labels and values for are drawn.
+    #[rustversion::attr(since(1.48), doc(alias = "PlotPieChart"))]
+    pub fn plot(&self, values: &[f64]) -> PlotItemResult {
+        let number_of_slices = self.slice_labels.len().min(values.len());
+        // If there is no data to plot, we stop here
+        if number_of_slices == 0 {
+            return PlotItemResult::not_plotted();
+        }
+
+        let label_pointers = self
+            .slice_labels
+            .iter()
+            .map(|label| label.as_ptr() as *const c_char)
+            .collect::<Vec<*const c_char>>();
+
+        self.style.apply();
+        unsafe {
+            sys::ImPlot_PlotPieChartdoublePtr(
+                label_pointers.as_ptr(),
+                values.as_ptr(),
+                number_of_slices as i32, // "as" casts saturate as of Rust 1.45. This is safe here.
+                self.center.x,
+                self.center.y,
+                self.radius,
+                self.normalize,
+                // "no label" is taken as null pointer in the C++ code, same as for heatmaps.
+                match &self.label_format {
+                    Some(format) => format.as_ptr() as *const c_char,
+                    None => std::ptr::null(),
+                },
+                self.start_angle,
+            );
+        }
+        PlotItemResult::from_last_item()
+    }
+}
+
+/// Create the format string used for pie chart slice values. The C++ code formats the values with
+/// `sprintf` into a 32 byte buffer, so instead of letting users pass arbitrary format strings (see
+/// `PlotHeatmap::with_label_format`), only `%g` with a limited number of significant digits is
+/// allowed here, which keeps the formatted values short enough for any input.
+fn pie_chart_label_format(significant_digits: u8) -> CString {
+    CString::new(format!("%.{}g", significant_digits.clamp(1, 15))).unwrap()
+}
+
+/// Sample types that can be plotted with [`PlotDigital`]. This is implemented for `u8`, `bool` and
+/// `f64` and cannot be implemented outside of this crate.
+pub trait DigitalSample: private::Sealed + Copy {
+    /// Convert a sample to the level it is drawn with.
+    #[doc(hidden)]
+    fn to_level(self) -> f64;
+}
+
+impl DigitalSample for f64 {
+    fn to_level(self) -> f64 {
+        self
+    }
+}
+
+impl DigitalSample for u8 {
+    fn to_level(self) -> f64 {
+        f64::from(self)
+    }
+}
+
+impl DigitalSample for bool {
+    fn to_level(self) -> f64 {
+        if self {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Struct to provide functionality for plotting digital (logic level) channels. Digital channels
+/// are drawn stacked at the bottom of the plot and do not scale with the Y axis - their height
+/// and the gap between them are set in pixels through the [`StyleVar::DigitalBitHeight`] and
+/// [`StyleVar::DigitalBitGap`] style variables.
+///
+/// [`StyleVar::DigitalBitHeight`]: enum.StyleVar.html#variant.DigitalBitHeight
+/// [`StyleVar::DigitalBitGap`]: enum.StyleVar.html#variant.DigitalBitGap
+pub struct PlotDigital {
+    /// Label to show in the legend for this channel
+    label: CString,
+
+    /// Style settings for this item only
+    style: ItemStyle,
+}
+
+impl PlotDigital {
+    /// Create a new digital channel to be plotted. Does not draw anything yet.
+    ///
+    /// # Panics
+    /// Will panic if the label string contains internal null bytes.
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: CString::new(label)
+                .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label)),
+            style: ItemStyle::default(),
+        }
+    }
+
+    /// Plot a digital channel. Use this in closures passed to
+    /// [`Plot::build()`](struct.Plot.html#method.build). The `x` values are the sample
+    /// positions, the `y` values the channel states, where anything other than 0 is drawn as a
+    /// high level (and values above 1 as proportionally higher levels). The states can be of
+    /// any [`DigitalSample`] type, such as captured `u8` or `bool` samples, while the positions
+    /// are always `f64` so that traces are not limited by the range of the sample type.
+    #[rustversion::attr(since(1.48), doc(alias = "PlotDigital"))]
+    pub fn plot<T: DigitalSample>(&self, x: &[f64], y: &[T]) -> PlotItemResult {
+        self.plot_strided(Strided::new(x), Strided::new(y))
+    }
+
+    /// Like `plot`, but with the values read from strided views, for example from fields of a
+    /// slice of structs. Since the positions and states are paired up one by one anyway, the
+    /// two views may have different strides and offsets.
+    #[rustversion::attr(since(1.48), doc(alias = "PlotDigital"))]
+    pub fn plot_strided<T: DigitalSample>(&self, x: Strided<f64>, y: Strided<T>) -> PlotItemResult {
+        // The C++ API only takes positions and states of the same type, so the two are paired
+        // up through the getter variant instead.
+        self.plot_fn(x.len().min(y.len()), |index| ImPlotPoint {
+            x: x.get(index),
+            y: y.get(index).to_level(),
+        })
+    }
+
+    /// Like `plot`, but with the points computed by `getter` for every index in `0..count`. The
+    /// x coordinate of each point is the sample position, the y coordinate the channel state.
+    /// The closure may be called several times for the same index, and in any order.
+    #[rustversion::attr(since(1.48), doc(alias = "PlotDigitalG"))]
+    pub fn plot_fn<F: FnMut(usize) -> ImPlotPoint>(
+        &self,
+        count: usize,
+        getter: F,
+    ) -> PlotItemResult {
+        // If there is no data to plot, we stop here
+        if count == 0 {
+            return PlotItemResult::not_plotted();
+        }
+        self.style.apply();
+        let mut getter = PointGetter::new(getter);
+        unsafe {
+            sys::ImPlot_PlotDigitalG(
+                self.label.as_ptr() as *const c_char,
+                getter.pointer_function(),
+                getter.data(),
+                count as i32, // "as" casts saturate as of Rust 1.45. This is safe here.
+                0,            // No offset
+            );
+        }
+        getter.finish();
+        PlotItemResult::from_last_item()
+    }
+}
+
+/// Struct to provide functionality for showing an image (in the form of an imgui texture)
+/// within a plot, placed in plot coordinates.
+pub struct PlotImage {
+    /// Label to show in the legend for this image
+    label: CString,
+
+    /// Texture coordinates of the lower left corner of the image
+    uv0: sys::ImVec2,
+
+    /// Texture coordinates of the upper right corner of the image
+    uv1: sys::ImVec2,
+
+    /// Tint color the image is multiplied with
+    tint: sys::ImVec4,
+
+    /// Style settings for this item only
+    style: ItemStyle,
+}
+
+impl PlotImage {
+    /// Create a new image to be shown. Uses the full texture without a tint by default.
+    /// Does not draw anything yet.
+    ///
+    /// # Panics
+    /// Will panic if the label string contains internal null bytes.
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: CString::new(label)
+                .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label)),
+            uv0: sys::ImVec2 { x: 0.0, y: 0.0 }, // Default value taken from C++ implot
+            uv1: sys::ImVec2 { x: 1.0, y: 1.0 }, // Default value taken from C++ implot
+            tint: sys::ImVec4 {
+                x: 1.0,
+                y: 1.0,
+                z: 1.0,
+                w: 1.0,
+            }, // Default value taken from C++ implot
+            style: ItemStyle::default(),
+        }
+    }
+
+    /// Specify the part of the texture to show, as texture coordinates of the lower left
+    /// (`uv0`) and upper right (`uv1`) corner.
+    pub fn with_uv(mut self, uv0: sys::ImVec2, uv1: sys::ImVec2) -> Self {
+        self.uv0 = uv0;
+        self.uv1 = uv1;
+        self
+    }
+
+    /// Specify a tint color the image is multiplied with. The components should be between 0.0
+    /// (no intensity) and 1.0 (full intensity).
+    pub fn with_tint(mut self, tint: sys::ImVec4) -> Self {
+        self.tint = tint;
+        self
+    }
+
+    /// Draw the image with the given texture, stretched over the area specified by the lower
+    /// left and upper right point in plot coordinates. These are called `bounds_min` and
+    /// `bounds_max` in the C++ code. Use this in closures passed to
+    /// [`Plot::build()`](struct.Plot.html#method.build)
+    #[rustversion::attr(since(1.48), doc(alias = "PlotImage"))]
+    pub fn plot(
+        &self,
+        texture_id: imgui::TextureId,
+        lower_left: ImPlotPoint,
+        upper_right: ImPlotPoint,
+    ) -> PlotItemResult {
+        self.style.apply();
+        unsafe {
+            sys::ImPlot_PlotImage(
+                self.label.as_ptr() as *const c_char,
+                texture_id.id() as sys::ImTextureID,
+                lower_left,
+                upper_right,
+                self.uv0,
+                self.uv1,
+                self.tint,
+            );
+        }
+        PlotItemResult::from_last_item()
+    }
+}
+
+/// Struct to provide functionality for annotating points in a plot with a text label. The text is
+/// formatted on the Rust side and passed to the C++ code through a fixed `"%s"` format string, so
+/// `%` characters in the text are shown as they are.
+pub struct Annotation {
+    /// Pixel offset of the label from the annotated point. Will be used independently of the
+    /// actual plot scaling. Defaults to no offset.
+    pixel_offset: sys::ImVec2,
+
+    /// Background color of the label. If this is `None`, the label is drawn without a background
+    /// in the color used for text within plots.
+    color: Option<sys::ImVec4>,
+
+    /// Whether the label is kept within the plot area when the annotated point is close to its
+    /// edges (or outside of it)
+    clamped: bool,
+}
+
+impl Annotation {
+    /// Create a new annotation without offset or color. Does not draw anything yet.
+    pub fn new() -> Self {
+        Self {
+            pixel_offset: sys::ImVec2 { x: 0.0, y: 0.0 },
+            color: None,
+            clamped: false,
+        }
+    }
+
+    /// Add a pixel offset to the label. This offset will be independent of the scaling of the
+    /// plot itself.
+    pub fn with_pixel_offset(mut self, offset_x: f32, offset_y: f32) -> Self {
+        self.pixel_offset = sys::ImVec2 {
+            x: offset_x,
+            y: offset_y,
+        };
+        self
+    }
+
+    /// Draw the label with the given background color. The components should be between 0.0
+    /// (no intensity) and 1.0 (full intensity).
+    pub fn with_color(mut self, color: sys::ImVec4) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Keep the label within the plot area, even if the annotated point is close to the edges
+    /// of the plot or outside of it.
+    #[rustversion::attr(since(1.48), doc(alias = "AnnotateClamped"))]
+    pub fn clamped(mut self) -> Self {
+        self.clamped = true;
+        self
+    }
+
+    /// Draw the annotation at the given point with the given text. Use this in closures passed
+    /// to [`Plot::build()`](struct.Plot.html#method.build)
+    ///
+    /// # Panics
+    /// Will panic if the text contains internal null bytes.
+    #[rustversion::attr(since(1.48), doc(alias = "Annotate"))]
+    pub fn plot(&self, point: ImPlotPoint, text: &str) {
+        let text = CString::new(text)
+            .unwrap_or_else(|_| panic!("Annotation text has internal null bytes: {}", text));
+        // The text is never used as the format string itself, so it does not matter what it
+        // contains - the C++ code just copies it over.
+        let format = b"%s\0".as_ptr() as *const c_char;
+        unsafe {
+            match (self.color, self.clamped) {
+                (None, false) => sys::ImPlot_AnnotateStr(
+                    point.x,
+                    point.y,
+                    self.pixel_offset,
+                    format,
+                    text.as_ptr(),
+                ),
+                (None, true) => sys::ImPlot_AnnotateClampedStr(
+                    point.x,
+                    point.y,
+                    self.pixel_offset,
+                    format,
+                    text.as_ptr(),
+                ),
+                (Some(color), false) => sys::ImPlot_AnnotateVec4(
+                    point.x,
+                    point.y,
+                    self.pixel_offset,
+                    color,
+                    format,
+                    text.as_ptr(),
+                ),
+                (Some(color), true) => sys::ImPlot_AnnotateClampedVec4(
+                    point.x,
+                    point.y,
+                    self.pixel_offset,
+                    color,
+                    format,
+                    text.as_ptr(),
+                ),
+            }
+        }
+    }
+
+    /// Draw the annotation at the given point with text created from the given format arguments,
+    /// for use with `format_args!`:
+    /// ```no_run
+    /// # use implot::{Annotation, ImPlotPoint};
+    /// let peak = ImPlotPoint { x: 2.0, y: 0.75 };
+    /// Annotation::new()
+    ///     .with_pixel_offset(10.0, -10.0)
+    ///     .plot_fmt(peak, format_args!("peak: {:.1}%", peak.y * 100.0));
+    /// ```
+    ///
+    /// # Panics
+    /// Will panic if the formatted text contains internal null bytes.
+    #[rustversion::attr(since(1.48), doc(alias = "Annotate"))]
+    pub fn plot_fmt(&self, point: ImPlotPoint, arguments: std::fmt::Arguments) {
+        self.plot(point, &std::fmt::format(arguments));
+    }
+}
+
+impl Default for Annotation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Used to seal traits in this module so they can only be implemented here, which allows adding
+// methods to them later on without that being a breaking change.
+mod private {
+    pub trait Sealed {}
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+    impl Sealed for i8 {}
+    impl Sealed for u8 {}
+    impl Sealed for i16 {}
+    impl Sealed for u16 {}
+    impl Sealed for i32 {}
+    impl Sealed for u32 {}
+    impl Sealed for i64 {}
+    impl Sealed for u64 {}
+    impl Sealed for bool {}
 }
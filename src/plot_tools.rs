@@ -0,0 +1,279 @@
+//! # Plot tools module
+//!
+//! This module defines interactive tools that can be placed in a plot, such as lines and
+//! points that the user can drag around. Unlike the things in `plot_elements`, these do not
+//! show up in the legend, and they report back whether the user changed their value.
+use crate::{plot_to_pixels_vec2, sys, PlotOrientation, IMPLOT_AUTO_COLOR};
+use crate::{ImPlotLimits, ImPlotPoint, ImPlotRange};
+use std::ffi::CString;
+use std::os::raw::c_char;
+
+/// Struct to provide functionality for a line that can be dragged with the mouse, for example to
+/// set thresholds or markers.
+pub struct DragLine {
+    /// Identifier of the line, also shown as its label if labels are enabled
+    id: CString,
+
+    /// Orientation of the line. A vertical line marks a value on the X axis, a horizontal line
+    /// a value on the Y axis.
+    orientation: PlotOrientation,
+
+    /// Color of the line. `None` means the color is taken from the current style.
+    color: Option<sys::ImVec4>,
+
+    /// Thickness of the line in pixels
+    thickness: f32,
+
+    /// Whether to show the current value next to the line
+    show_label: bool,
+}
+
+impl DragLine {
+    /// Create a new vertical drag line with the given ID. Does not draw anything yet.
+    ///
+    /// # Panics
+    /// Will panic if the ID string contains internal null bytes.
+    pub fn new(id: &str) -> Self {
+        Self {
+            id: CString::new(id)
+                .unwrap_or_else(|_| panic!("ID string has internal null bytes: {}", id)),
+            orientation: PlotOrientation::Vertical,
+            color: None,
+            thickness: 1.0,   // Default value taken from C++ implot
+            show_label: true, // Default value taken from C++ implot
+        }
+    }
+
+    /// Set the orientation of the line. A vertical line (the default) is dragged along the X
+    /// axis, a horizontal line along the Y axis.
+    pub fn with_orientation(mut self, orientation: PlotOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set the color of the line. The components should be between 0.0 (no intensity) and 1.0
+    /// (full intensity).
+    pub fn with_color(mut self, color: sys::ImVec4) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Set the thickness of the line, in pixels
+    pub fn with_thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Set whether the current value is shown next to the line
+    pub fn with_label(mut self, show_label: bool) -> Self {
+        self.show_label = show_label;
+        self
+    }
+
+    /// Draw the line at the given value and let the user drag it. Returns true if the value was
+    /// changed by the user this frame. Use this in closures passed to
+    /// [`Plot::build()`](struct.Plot.html#method.build)
+    #[rustversion::attr(since(1.48), doc(alias = "DragLineX"))]
+    #[rustversion::attr(since(1.48), doc(alias = "DragLineY"))]
+    pub fn build(&self, value: &mut f64) -> bool {
+        let color = self.color.unwrap_or(IMPLOT_AUTO_COLOR);
+        unsafe {
+            match self.orientation {
+                PlotOrientation::Vertical => sys::ImPlot_DragLineX(
+                    self.id.as_ptr() as *const c_char,
+                    value as *mut f64,
+                    self.show_label,
+                    color,
+                    self.thickness,
+                ),
+                PlotOrientation::Horizontal => sys::ImPlot_DragLineY(
+                    self.id.as_ptr() as *const c_char,
+                    value as *mut f64,
+                    self.show_label,
+                    color,
+                    self.thickness,
+                ),
+            }
+        }
+    }
+}
+
+/// Internally-used enum for storing how a drag point snaps to positions
+enum PointSnapping<'a> {
+    /// No snapping, the point follows the mouse freely
+    None,
+    /// Snapping to a regular grid with the given step sizes in X and Y direction
+    Grid { x_step: f64, y_step: f64 },
+    /// Snapping to the closest point (in pixel terms) of a data series
+    Data { x: &'a [f64], y: &'a [f64] },
+}
+
+/// Struct to provide functionality for a point that can be dragged with the mouse, for example
+/// for picking positions or setting control points.
+pub struct DragPoint<'a> {
+    /// Identifier of the point, also shown as its label if labels are enabled
+    id: CString,
+
+    /// Color of the point. `None` means the color is taken from the current style.
+    color: Option<sys::ImVec4>,
+
+    /// Radius of the point in pixels
+    radius: f32,
+
+    /// Whether to show the current position next to the point
+    show_label: bool,
+
+    /// How the point snaps to positions after being dragged
+    snapping: PointSnapping<'a>,
+
+    /// Region the point is kept within, if any
+    clamp_region: Option<ImPlotLimits>,
+}
+
+impl<'a> DragPoint<'a> {
+    /// Create a new drag point with the given ID. Does not draw anything yet.
+    ///
+    /// # Panics
+    /// Will panic if the ID string contains internal null bytes.
+    pub fn new(id: &str) -> Self {
+        Self {
+            id: CString::new(id)
+                .unwrap_or_else(|_| panic!("ID string has internal null bytes: {}", id)),
+            color: None,
+            radius: 4.0,      // Default value taken from C++ implot
+            show_label: true, // Default value taken from C++ implot
+            snapping: PointSnapping::None,
+            clamp_region: None,
+        }
+    }
+
+    /// Set the color of the point. The components should be between 0.0 (no intensity) and 1.0
+    /// (full intensity).
+    pub fn with_color(mut self, color: sys::ImVec4) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Set the radius of the point, in pixels
+    pub fn with_radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Set whether the current position is shown next to the point
+    pub fn with_label(mut self, show_label: bool) -> Self {
+        self.show_label = show_label;
+        self
+    }
+
+    /// Snap the point to a grid with the given step sizes (in plot coordinates) when dragged.
+    /// Step sizes that are not positive leave the respective coordinate as it is.
+    ///
+    /// Note: This conflicts with `with_data_snapping`, whichever is called last takes effect.
+    pub fn with_grid_snapping(mut self, x_step: f64, y_step: f64) -> Self {
+        self.snapping = PointSnapping::Grid { x_step, y_step };
+        self
+    }
+
+    /// Snap the point to the closest point of the given data series when dragged. Closeness is
+    /// measured in pixels, so this works as expected for axes with very different scales. If
+    /// the slices differ in length, the extra values in the longer one are ignored.
+    ///
+    /// Note: This conflicts with `with_grid_snapping`, whichever is called last takes effect.
+    pub fn with_data_snapping(mut self, x: &'a [f64], y: &'a [f64]) -> Self {
+        self.snapping = PointSnapping::Data { x, y };
+        self
+    }
+
+    /// Keep the point within the given region (in plot coordinates). Clamping happens after
+    /// snapping.
+    pub fn with_clamping<X: Into<ImPlotRange>, Y: Into<ImPlotRange>>(
+        mut self,
+        x_range: X,
+        y_range: Y,
+    ) -> Self {
+        self.clamp_region = Some(ImPlotLimits {
+            X: x_range.into(),
+            Y: y_range.into(),
+        });
+        self
+    }
+
+    /// Draw the point at the given position and let the user drag it. Returns true if the
+    /// position was changed by the user this frame. Use this in closures passed to
+    /// [`Plot::build()`](struct.Plot.html#method.build)
+    #[rustversion::attr(since(1.48), doc(alias = "DragPoint"))]
+    pub fn build(&self, point: &mut ImPlotPoint) -> bool {
+        let previous = *point;
+        let dragged = unsafe {
+            sys::ImPlot_DragPoint(
+                self.id.as_ptr() as *const c_char,
+                &mut point.x as *mut f64,
+                &mut point.y as *mut f64,
+                self.show_label,
+                self.color.unwrap_or(IMPLOT_AUTO_COLOR),
+                self.radius,
+            )
+        };
+
+        if dragged {
+            self.snap(point);
+            if let Some(region) = &self.clamp_region {
+                point.x = clamp_to_range(point.x, &region.X);
+                point.y = clamp_to_range(point.y, &region.Y);
+            }
+        }
+
+        // With snapping, the point might not have moved even though it was dragged.
+        point.x != previous.x || point.y != previous.y
+    }
+
+    /// Internal helper function to apply the snapping setting to a dragged point.
+    fn snap(&self, point: &mut ImPlotPoint) {
+        match self.snapping {
+            PointSnapping::None => {}
+            PointSnapping::Grid { x_step, y_step } => {
+                if x_step > 0.0 {
+                    point.x = (point.x / x_step).round() * x_step;
+                }
+                if y_step > 0.0 {
+                    point.y = (point.y / y_step).round() * y_step;
+                }
+            }
+            PointSnapping::Data { x, y } => {
+                let dragged_pixels = plot_to_pixels_vec2(point, None);
+                let closest = x
+                    .iter()
+                    .zip(y.iter())
+                    .map(|(x, y)| {
+                        let candidate = ImPlotPoint { x: *x, y: *y };
+                        let pixels = plot_to_pixels_vec2(&candidate, None);
+                        let distance = (pixels.x - dragged_pixels.x).powi(2)
+                            + (pixels.y - dragged_pixels.y).powi(2);
+                        (candidate, distance)
+                    })
+                    .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+                if let Some((candidate, _)) = closest {
+                    *point = candidate;
+                }
+            }
+        }
+    }
+}
+
+/// Clamp a value to a range, regardless of whether the range's minimum and maximum are in the
+/// expected order.
+fn clamp_to_range(value: f64, range: &ImPlotRange) -> f64 {
+    let (lower, upper) = if range.Min <= range.Max {
+        (range.Min, range.Max)
+    } else {
+        (range.Max, range.Min)
+    };
+    if value < lower {
+        lower
+    } else if value > upper {
+        upper
+    } else {
+        value
+    }
+}
@@ -0,0 +1,238 @@
+//! # Strided data module
+//!
+//! This module defines [`Strided`], a view of values that are spread out in memory with a
+//! constant distance between them, such as one field of a slice of structs or one component
+//! of an interleaved buffer. Plot elements accept these through their `plot_strided` functions,
+//! which lets ImPlot read the data in place without copying it. Heatmaps and pie charts have
+//! no such functions, as ImPlot can only read their values from contiguous memory.
+
+use std::marker::PhantomData;
+
+/// A read-only view of `T` values with a constant distance (the stride) between them, plus an
+/// offset for rotating the data, as is useful for ring buffers. Can be built from plain slices,
+/// interleaved buffers or a field of a slice of records:
+/// ```no_run
+/// # use implot::Strided;
+/// struct Sample {
+///     t: f64,
+///     v: f64,
+///     flags: u32,
+/// }
+/// let samples: Vec<Sample> = vec![];
+/// let times = Strided::from_records(&samples, |sample| &sample.t);
+/// let values = Strided::from_records(&samples, |sample| &sample.v);
+///
+/// let interleaved = [0.0, 1.0, 0.5, 2.0, 1.0, 0.0]; // x0, y0, x1, y1, x2, y2
+/// let xs = Strided::interleaved(&interleaved, 2, 0);
+/// let ys = Strided::interleaved(&interleaved, 2, 1);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Strided<'a, T> {
+    /// Pointer to the first value
+    data: *const T,
+    /// Number of values
+    count: usize,
+    /// Distance between consecutive values in bytes
+    stride: usize,
+    /// Index of the value that is treated as the first one
+    offset: usize,
+    /// The values are borrowed from somewhere else
+    marker: PhantomData<&'a T>,
+}
+
+impl<'a, T: Copy> Strided<'a, T> {
+    /// View all values of a slice, one after the other.
+    pub fn new(values: &'a [T]) -> Self {
+        Self {
+            data: values.as_ptr(),
+            count: values.len(),
+            stride: std::mem::size_of::<T>(),
+            offset: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// View one component of an interleaved buffer, where each record consists of `components`
+    /// consecutive values. `component` selects which of them to view, for example 0 for the x
+    /// and 1 for the y values of an `[x0, y0, x1, y1, ...]` buffer. Incomplete records at the
+    /// end of the buffer are ignored.
+    ///
+    /// # Panics
+    /// Will panic if `component` is not smaller than `components`.
+    pub fn interleaved(values: &'a [T], components: usize, component: usize) -> Self {
+        assert!(
+            component < components,
+            "Component {} does not exist in records of {} values",
+            component,
+            components
+        );
+        Self {
+            // If the buffer is shorter than one record, the count is zero and this pointer is
+            // never read from, so it doesn't matter that it is out of bounds.
+            data: values.as_ptr().wrapping_add(component),
+            count: values.len() / components,
+            stride: components * std::mem::size_of::<T>(),
+            offset: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// View one field of a slice of records. The `field` function has to return a reference to
+    /// the same field for every record, which is checked for all records.
+    ///
+    /// # Panics
+    /// Will panic if `field` returns a reference to something other than a field of the record
+    /// passed to it, or to different fields for different records.
+    pub fn from_records<R, F: Fn(&'a R) -> &'a T>(records: &'a [R], field: F) -> Self {
+        let record_size = std::mem::size_of::<R>();
+        let field_offset = match records.first() {
+            Some(first_record) => {
+                let field_address = field(first_record) as *const T as usize;
+                let record_address = first_record as *const R as usize;
+                assert!(
+                    field_address >= record_address
+                        && field_address - record_address + std::mem::size_of::<T>() <= record_size,
+                    "The field function has to return a reference into the record"
+                );
+                field_address - record_address
+            }
+            None => 0,
+        };
+        // The offset has to be the same for all records, otherwise the data could be read from
+        // a different field or from padding bytes.
+        for record in records {
+            assert_eq!(
+                field(record) as *const T as usize,
+                record as *const R as usize + field_offset,
+                "The field function has to return the same field for every record"
+            );
+        }
+
+        Self {
+            data: match records.first() {
+                Some(first_record) => field(first_record) as *const T,
+                None => std::ptr::NonNull::dangling().as_ptr(),
+            },
+            count: records.len(),
+            stride: record_size,
+            offset: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Set the index of the value that is treated as the first one. The values are rotated, so
+    /// values before the offset are shown after the last one. For a ring buffer, this is the
+    /// index of the oldest value.
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Number of values in this view.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Whether this view contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// View `count` values starting at `data`, `stride` bytes apart.
+    ///
+    /// # Safety
+    /// All of the values have to be valid and borrowed for `'a`.
+    #[cfg(feature = "ndarray")]
+    pub(crate) unsafe fn from_raw_parts(data: *const T, count: usize, stride: usize) -> Self {
+        Self {
+            data,
+            count,
+            stride,
+            offset: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Pointer to the first value, for passing to the C++ API.
+    pub(crate) fn as_ptr(&self) -> *const T {
+        self.data
+    }
+
+    /// Get the value at the given index, counting from the offset like the C++ API does.
+    ///
+    /// # Panics
+    /// Will panic if the index is out of bounds.
+    pub(crate) fn get(&self, index: usize) -> T {
+        assert!(
+            index < self.count,
+            "Index {} is out of bounds for {} values",
+            index,
+            self.count
+        );
+        let index = (index + self.offset) % self.count;
+        // The constructors make sure that all values up to the count are within the borrowed
+        // data, and at the right distance from each other.
+        unsafe { *((self.data as *const u8).add(index * self.stride) as *const T) }
+    }
+}
+
+impl<'a, T: Copy> From<&'a [T]> for Strided<'a, T> {
+    fn from(values: &'a [T]) -> Self {
+        Self::new(values)
+    }
+}
+
+impl<'a, T: Copy> From<&'a Vec<T>> for Strided<'a, T> {
+    fn from(values: &'a Vec<T>) -> Self {
+        Self::new(values)
+    }
+}
+
+/// Memory layout shared by the x and y values of a plot item, as passed to the C++ API.
+pub(crate) struct SharedLayout {
+    /// Number of points
+    pub count: i32,
+    /// Index of the first point
+    pub offset: i32,
+    /// Distance between consecutive values in bytes
+    pub stride: i32,
+}
+
+/// Get the layout that x and y values share in the C++ API, which only takes one offset and
+/// stride for both. Returns `None` if there are no points to plot.
+///
+/// # Panics
+/// Will panic if the strides or offsets of the two views differ.
+pub(crate) fn shared_layout<T>(x: &Strided<T>, y: &Strided<T>) -> Option<SharedLayout> {
+    shared_layout_of(&[x, y])
+}
+
+/// Like `shared_layout`, for any number of views that are passed to the C++ API together, such
+/// as the positions and errors of error bars.
+///
+/// # Panics
+/// Will panic if the strides or offsets of the views differ.
+pub(crate) fn shared_layout_of<T>(views: &[&Strided<T>]) -> Option<SharedLayout> {
+    let first = views.first()?;
+    for view in views {
+        assert_eq!(
+            first.stride, view.stride,
+            "The values of a plot item have to have the same stride"
+        );
+        assert_eq!(
+            first.offset, view.offset,
+            "The values of a plot item have to have the same offset"
+        );
+    }
+    let count = views.iter().map(|view| view.count).min().unwrap_or(0);
+    if count == 0 {
+        return None;
+    }
+    // "as" casts saturate as of Rust 1.45. This is safe here, the offset is reduced to below
+    // the count first, as the C++ code wraps it around anyway.
+    Some(SharedLayout {
+        count: count as i32,
+        offset: (first.offset % count) as i32,
+        stride: first.stride as i32,
+    })
+}
@@ -0,0 +1,189 @@
+//! # Style module
+//!
+//! This module defines [`PlotStyle`], a typed view of the style settings of the current
+//! ImPlot context. A copy of it is obtained through [`Context::style`] and written back with
+//! [`Context::set_style`].
+//!
+//! [`Context::style`]: crate::Context::style
+//! [`Context::set_style`]: crate::Context::set_style
+
+use std::ops::{Index, IndexMut};
+
+use crate::{sys, ImVec2, ImVec4, Marker, PlotColorElement};
+
+/// Style settings of an ImPlot context. Changes made through the setters become persistent
+/// once the style is passed to [`Context::set_style`](crate::Context::set_style), as opposed to
+/// the temporary changes made through `push_style_var_*` and `push_style_color`.
+/// Colors can be read and written by indexing with a [`PlotColorElement`].
+#[rustversion::attr(since(1.48), doc(alias = "ImPlotStyle"))]
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug)]
+pub struct PlotStyle {
+    raw: sys::ImPlotStyle,
+}
+
+// Implements a getter and a setter for each of the given fields of the raw style
+macro_rules! impl_style_accessors {
+    ($($(#[$attr:meta])* $field:ident: $ty:ty => $getter:ident, $setter:ident;)*) => {
+        $(
+            $(#[$attr])*
+            pub fn $getter(&self) -> $ty {
+                self.raw.$field
+            }
+
+            $(#[$attr])*
+            pub fn $setter(&mut self, value: $ty) {
+                self.raw.$field = value;
+            }
+        )*
+    };
+}
+
+impl PlotStyle {
+    /// Create a shared reference to a `PlotStyle` from a pointer to the raw style.
+    ///
+    /// # Safety
+    /// The pointer must be non-null and point to a valid `ImPlotStyle` which outlives the
+    /// returned reference and is not modified while the reference is in use.
+    pub(crate) unsafe fn from_raw<'a>(raw: *const sys::ImPlotStyle) -> &'a Self {
+        assert_ne!(raw, std::ptr::null());
+        // PlotStyle is repr(transparent), so this cast is fine
+        &*(raw as *const Self)
+    }
+
+    /// Create a mutable reference to a `PlotStyle` from a pointer to the raw style.
+    ///
+    /// # Safety
+    /// The pointer must be non-null and point to a valid `ImPlotStyle` which outlives the
+    /// returned reference and is not accessed otherwise while the reference is in use.
+    pub(crate) unsafe fn from_raw_mut<'a>(raw: *mut sys::ImPlotStyle) -> &'a mut Self {
+        assert_ne!(raw, std::ptr::null_mut());
+        // PlotStyle is repr(transparent), so this cast is fine
+        &mut *(raw as *mut Self)
+    }
+
+    /// Get the raw style struct, for settings not exposed by this wrapper.
+    pub fn raw(&self) -> &sys::ImPlotStyle {
+        &self.raw
+    }
+
+    /// Get the raw style struct mutably, for settings not exposed by this wrapper.
+    pub fn raw_mut(&mut self) -> &mut sys::ImPlotStyle {
+        &mut self.raw
+    }
+
+    impl_style_accessors! {
+        /// Item line weight in pixels
+        LineWeight: f32 => line_weight, set_line_weight;
+        /// Marker size in pixels (roughly the marker's "radius")
+        MarkerSize: f32 => marker_size, set_marker_size;
+        /// Outline weight of markers in pixels
+        MarkerWeight: f32 => marker_weight, set_marker_weight;
+        /// Alpha modifier applied to all plot item fills
+        FillAlpha: f32 => fill_alpha, set_fill_alpha;
+        /// Error bar whisker width in pixels
+        ErrorBarSize: f32 => error_bar_size, set_error_bar_size;
+        /// Error bar whisker weight in pixels
+        ErrorBarWeight: f32 => error_bar_weight, set_error_bar_weight;
+        /// Digital channels bit height (at 1) in pixels
+        DigitalBitHeight: f32 => digital_bit_height, set_digital_bit_height;
+        /// Digital channels bit padding gap in pixels
+        DigitalBitGap: f32 => digital_bit_gap, set_digital_bit_gap;
+        /// Thickness of border around plot area
+        PlotBorderSize: f32 => plot_border_size, set_plot_border_size;
+        /// Alpha multiplier applied to minor axis grid lines
+        MinorAlpha: f32 => minor_alpha, set_minor_alpha;
+        /// Major tick lengths for X and Y axes
+        MajorTickLen: ImVec2 => major_tick_len, set_major_tick_len;
+        /// Minor tick lengths for X and Y axes
+        MinorTickLen: ImVec2 => minor_tick_len, set_minor_tick_len;
+        /// Line thickness of major ticks
+        MajorTickSize: ImVec2 => major_tick_size, set_major_tick_size;
+        /// Line thickness of minor ticks
+        MinorTickSize: ImVec2 => minor_tick_size, set_minor_tick_size;
+        /// Line thickness of major grid lines
+        MajorGridSize: ImVec2 => major_grid_size, set_major_grid_size;
+        /// Line thickness of minor grid lines
+        MinorGridSize: ImVec2 => minor_grid_size, set_minor_grid_size;
+        /// Padding between widget frame and plot area and/or labels
+        PlotPadding: ImVec2 => plot_padding, set_plot_padding;
+        /// Padding between axes labels, tick labels, and plot edge
+        LabelPadding: ImVec2 => label_padding, set_label_padding;
+        /// Legend padding from top-left of plot
+        LegendPadding: ImVec2 => legend_padding, set_legend_padding;
+        /// Legend inner padding from legend edges
+        LegendInnerPadding: ImVec2 => legend_inner_padding, set_legend_inner_padding;
+        /// Spacing between legend entries
+        LegendSpacing: ImVec2 => legend_spacing, set_legend_spacing;
+        /// Padding between plot edge and interior mouse location text
+        MousePosPadding: ImVec2 => mouse_pos_padding, set_mouse_pos_padding;
+        /// Text padding around annotation labels
+        AnnotationPadding: ImVec2 => annotation_padding, set_annotation_padding;
+        /// Additional fit padding as a percentage of the fit extents (e.g. 0.1 adds 10%)
+        FitPadding: ImVec2 => fit_padding, set_fit_padding;
+        /// Default size used for plots whose size is set to [0.0, 0.0]
+        PlotDefaultSize: ImVec2 => plot_default_size, set_plot_default_size;
+        /// Minimum size the plot frame can be when shrunk
+        PlotMinSize: ImVec2 => plot_min_size, set_plot_min_size;
+        /// Whether lines are drawn anti-aliased (this can be expensive for large data)
+        AntiAliasedLines: bool => anti_aliased_lines, set_anti_aliased_lines;
+        /// Whether time axes use local time instead of UTC
+        UseLocalTime: bool => use_local_time, set_use_local_time;
+        /// Whether dates on time axes are formatted according to ISO 8601
+        UseISO8601: bool => use_iso_8601, set_use_iso_8601;
+        /// Whether times on time axes use the 24 hour clock instead of AM/PM
+        Use24HourClock: bool => use_24_hour_clock, set_use_24_hour_clock;
+    }
+
+    /// Default marker type for plot items.
+    pub fn marker(&self) -> Marker {
+        marker_from_raw(self.raw.Marker)
+    }
+
+    /// Set the default marker type for plot items.
+    pub fn set_marker(&mut self, marker: Marker) {
+        self.raw.Marker = marker as i32;
+    }
+
+    /// Get the color of the given element.
+    pub fn color(&self, element: PlotColorElement) -> ImVec4 {
+        self[element]
+    }
+
+    /// Set the color of the given element. Setting the alpha value to -1.0 makes ImPlot
+    /// pick the color automatically, which is the default for most elements.
+    pub fn set_color(&mut self, element: PlotColorElement, color: ImVec4) {
+        self[element] = color;
+    }
+}
+
+impl Index<PlotColorElement> for PlotStyle {
+    type Output = ImVec4;
+
+    fn index(&self, element: PlotColorElement) -> &ImVec4 {
+        &self.raw.Colors[element as usize]
+    }
+}
+
+impl IndexMut<PlotColorElement> for PlotStyle {
+    fn index_mut(&mut self, element: PlotColorElement) -> &mut ImVec4 {
+        &mut self.raw.Colors[element as usize]
+    }
+}
+
+/// Convert a raw marker value to a `Marker`, falling back to no marker for unknown values.
+fn marker_from_raw(raw: i32) -> Marker {
+    match raw {
+        sys::ImPlotMarker__ImPlotMarker_Circle => Marker::Circle,
+        sys::ImPlotMarker__ImPlotMarker_Square => Marker::Square,
+        sys::ImPlotMarker__ImPlotMarker_Diamond => Marker::Diamond,
+        sys::ImPlotMarker__ImPlotMarker_Up => Marker::Up,
+        sys::ImPlotMarker__ImPlotMarker_Down => Marker::Down,
+        sys::ImPlotMarker__ImPlotMarker_Left => Marker::Left,
+        sys::ImPlotMarker__ImPlotMarker_Right => Marker::Right,
+        sys::ImPlotMarker__ImPlotMarker_Cross => Marker::Cross,
+        sys::ImPlotMarker__ImPlotMarker_Plus => Marker::Plus,
+        sys::ImPlotMarker__ImPlotMarker_Asterisk => Marker::Asterisk,
+        _ => Marker::None,
+    }
+}
@@ -0,0 +1,296 @@
+//! # Theme module
+//!
+//! This module contains [`PlotTheme`], a serializable snapshot of the complete style of a
+//! context, and the functions on [`Context`] that save and load it. It is only available with
+//! the `serde` feature enabled.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    current_colormap_preset, set_colormap_from_preset, Colormap, Context, ImVec2, ImVec4, Marker,
+    PlotColorElement, PlotStyle,
+};
+
+/// All colorable elements, in the order in which they are stored in the style.
+const ALL_COLOR_ELEMENTS: [PlotColorElement; 24] = [
+    PlotColorElement::Line,
+    PlotColorElement::Fill,
+    PlotColorElement::MarkerOutline,
+    PlotColorElement::MarkerFill,
+    PlotColorElement::ErrorBar,
+    PlotColorElement::FrameBg,
+    PlotColorElement::PlotBg,
+    PlotColorElement::PlotBorder,
+    PlotColorElement::LegendBackground,
+    PlotColorElement::LegendBorder,
+    PlotColorElement::LegendText,
+    PlotColorElement::TitleText,
+    PlotColorElement::InlayText,
+    PlotColorElement::XAxis,
+    PlotColorElement::XAxisGrid,
+    PlotColorElement::YAxis,
+    PlotColorElement::YAxisGrid,
+    PlotColorElement::YAxis2,
+    PlotColorElement::YAxisGrid2,
+    PlotColorElement::YAxis3,
+    PlotColorElement::YAxisGrid3,
+    PlotColorElement::Selection,
+    PlotColorElement::Crosshairs,
+    PlotColorElement::Query,
+];
+
+/// Conversion between the values stored in a theme and the values of a [`PlotStyle`]. This
+/// mainly exists because the sys vector types don't implement the serde traits.
+trait ThemeValue: Copy {
+    type Style;
+
+    fn from_style(value: Self::Style) -> Self;
+    fn into_style(self) -> Self::Style;
+}
+
+impl ThemeValue for f32 {
+    type Style = f32;
+
+    fn from_style(value: f32) -> Self {
+        value
+    }
+
+    fn into_style(self) -> f32 {
+        self
+    }
+}
+
+impl ThemeValue for bool {
+    type Style = bool;
+
+    fn from_style(value: bool) -> Self {
+        value
+    }
+
+    fn into_style(self) -> bool {
+        self
+    }
+}
+
+impl ThemeValue for Marker {
+    type Style = Marker;
+
+    fn from_style(value: Marker) -> Self {
+        value
+    }
+
+    fn into_style(self) -> Marker {
+        self
+    }
+}
+
+impl ThemeValue for [f32; 2] {
+    type Style = ImVec2;
+
+    fn from_style(value: ImVec2) -> Self {
+        [value.x, value.y]
+    }
+
+    fn into_style(self) -> ImVec2 {
+        ImVec2 {
+            x: self[0],
+            y: self[1],
+        }
+    }
+}
+
+// Defines the ThemeStyleVars struct from a list of keys along with the accessors of PlotStyle
+// they correspond to.
+macro_rules! define_theme_style_vars {
+    ($($key:tt => $field:ident: $ty:ty, $getter:ident, $setter:ident;)*) => {
+        /// The non-color style settings of a [`PlotTheme`]. These are all the [`StyleVar`]
+        /// values, keyed by the name of the `StyleVar` variant, plus the anti-aliasing and time
+        /// formatting options of the style. Settings that are `None` are left unchanged when
+        /// the theme is loaded.
+        ///
+        /// [`StyleVar`]: crate::StyleVar
+        #[derive(Clone, Debug, Default, Serialize, Deserialize)]
+        #[serde(deny_unknown_fields)]
+        pub struct ThemeStyleVars {
+            $(
+                #[serde(rename = $key, default, skip_serializing_if = "Option::is_none")]
+                pub $field: Option<$ty>,
+            )*
+        }
+
+        impl ThemeStyleVars {
+            /// Read all the settings from the given style.
+            fn from_style(style: &PlotStyle) -> Self {
+                Self {
+                    $($field: Some(ThemeValue::from_style(style.$getter())),)*
+                }
+            }
+
+            /// Write the settings that are set to the given style.
+            fn apply_to(&self, style: &mut PlotStyle) {
+                $(
+                    if let Some(value) = self.$field {
+                        style.$setter(value.into_style());
+                    }
+                )*
+            }
+        }
+    };
+}
+
+define_theme_style_vars! {
+    "LineWeight" => line_weight: f32, line_weight, set_line_weight;
+    "Marker" => marker: Marker, marker, set_marker;
+    "MarkerSize" => marker_size: f32, marker_size, set_marker_size;
+    "MarkerWeight" => marker_weight: f32, marker_weight, set_marker_weight;
+    "FillAlpha" => fill_alpha: f32, fill_alpha, set_fill_alpha;
+    "ErrorBarSize" => error_bar_size: f32, error_bar_size, set_error_bar_size;
+    "ErrorBarWeight" => error_bar_weight: f32, error_bar_weight, set_error_bar_weight;
+    "DigitalBitHeight" => digital_bit_height: f32, digital_bit_height, set_digital_bit_height;
+    "DigitalBitGap" => digital_bit_gap: f32, digital_bit_gap, set_digital_bit_gap;
+    "PlotBorderSize" => plot_border_size: f32, plot_border_size, set_plot_border_size;
+    "MinorAlpha" => minor_alpha: f32, minor_alpha, set_minor_alpha;
+    "MajorTickLen" => major_tick_len: [f32; 2], major_tick_len, set_major_tick_len;
+    "MinorTickLen" => minor_tick_len: [f32; 2], minor_tick_len, set_minor_tick_len;
+    "MajorTickSize" => major_tick_size: [f32; 2], major_tick_size, set_major_tick_size;
+    "MinorTickSize" => minor_tick_size: [f32; 2], minor_tick_size, set_minor_tick_size;
+    "MajorGridSize" => major_grid_size: [f32; 2], major_grid_size, set_major_grid_size;
+    "MinorGridSize" => minor_grid_size: [f32; 2], minor_grid_size, set_minor_grid_size;
+    "PlotPadding" => plot_padding: [f32; 2], plot_padding, set_plot_padding;
+    "LabelPadding" => label_padding: [f32; 2], label_padding, set_label_padding;
+    "LegendPadding" => legend_padding: [f32; 2], legend_padding, set_legend_padding;
+    "LegendInnerPadding" => legend_inner_padding: [f32; 2], legend_inner_padding,
+        set_legend_inner_padding;
+    "LegendSpacing" => legend_spacing: [f32; 2], legend_spacing, set_legend_spacing;
+    "MousePosPadding" => mouse_pos_padding: [f32; 2], mouse_pos_padding,
+        set_mouse_pos_padding;
+    "AnnotationPadding" => annotation_padding: [f32; 2], annotation_padding,
+        set_annotation_padding;
+    "FitPadding" => fit_padding: [f32; 2], fit_padding, set_fit_padding;
+    "PlotDefaultSize" => plot_default_size: [f32; 2], plot_default_size, set_plot_default_size;
+    "PlotMinSize" => plot_min_size: [f32; 2], plot_min_size, set_plot_min_size;
+    "AntiAliasedLines" => anti_aliased_lines: bool, anti_aliased_lines, set_anti_aliased_lines;
+    "UseLocalTime" => use_local_time: bool, use_local_time, set_use_local_time;
+    "UseISO8601" => use_iso_8601: bool, use_iso_8601, set_use_iso_8601;
+    "Use24HourClock" => use_24_hour_clock: bool, use_24_hour_clock, set_use_24_hour_clock;
+}
+
+/// A serializable plot theme, consisting of style settings, element colors and optionally a
+/// colormap preset. Themes can be stored in any format with a serde implementation, such as
+/// JSON or TOML. All keys are the names of the corresponding enum variants in this crate, for
+/// example a theme in JSON format could look like this:
+///
+/// ```json
+/// {
+///   "StyleVars": { "LineWeight": 2.0, "Marker": "Circle", "PlotPadding": [10.0, 10.0] },
+///   "Colors": { "PlotBg": [0.1, 0.1, 0.1, 1.0], "Line": [0.0, 0.0, 0.0, -1.0] },
+///   "Colormap": "Viridis"
+/// }
+/// ```
+///
+/// Keys that are left out keep their current value when the theme is loaded, while unknown keys
+/// and values of the wrong type are rejected when deserializing.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "PascalCase")]
+pub struct PlotTheme {
+    /// Style settings other than colors
+    #[serde(default)]
+    pub style_vars: ThemeStyleVars,
+    /// Element colors as RGBA values between 0.0 and 1.0. An alpha value of -1.0 makes ImPlot
+    /// pick the color of the element automatically.
+    #[serde(default)]
+    pub colors: BTreeMap<PlotColorElement, [f32; 4]>,
+    /// Colormap preset to switch to when loading the theme
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub colormap: Option<Colormap>,
+}
+
+impl PlotTheme {
+    /// Create a theme containing all the settings and colors of the given style. The colormap
+    /// is not part of the style and left unset, use [`Context::theme`] to include it.
+    pub fn from_style(style: &PlotStyle) -> Self {
+        Self {
+            style_vars: ThemeStyleVars::from_style(style),
+            colors: ALL_COLOR_ELEMENTS
+                .iter()
+                .map(|&element| {
+                    let color = style[element];
+                    (element, [color.x, color.y, color.z, color.w])
+                })
+                .collect(),
+            colormap: None,
+        }
+    }
+
+    /// Write the settings and colors contained in this theme to the given style. The colormap
+    /// is not part of the style, use [`Context::apply_theme`] to switch to it as well.
+    pub fn apply_to(&self, style: &mut PlotStyle) {
+        self.style_vars.apply_to(style);
+        for (&element, color) in self.colors.iter() {
+            style[element] = ImVec4 {
+                x: color[0],
+                y: color[1],
+                z: color[2],
+                w: color[3],
+            };
+        }
+    }
+}
+
+impl Context {
+    /// Get the current style of this context as a theme. See [`PlotTheme::from_style`] for
+    /// what is contained in it. The colormap is set to the current preset as returned by
+    /// [`current_colormap_preset`], and left unset if a custom colormap is in use.
+    pub fn theme(&self) -> PlotTheme {
+        PlotTheme {
+            colormap: current_colormap_preset(),
+            ..PlotTheme::from_style(&self.style())
+        }
+    }
+
+    /// Apply the given theme to this context, including the colormap if the theme has one.
+    pub fn apply_theme(&mut self, theme: &PlotTheme) {
+        let mut style = self.style();
+        theme.apply_to(&mut style);
+        self.set_style(&style);
+        if let Some(colormap) = theme.colormap {
+            set_colormap_from_preset(colormap, 0);
+        }
+    }
+
+    /// Save the current style of this context, including the colormap preset, with the given
+    /// serializer.
+    ///
+    /// ```no_run
+    /// # let plot_context = implot::Context::create();
+    /// let mut theme = Vec::new();
+    /// plot_context
+    ///     .save_theme(&mut serde_json::Serializer::pretty(&mut theme))
+    ///     .expect("Could not save the theme");
+    /// ```
+    pub fn save_theme<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.theme().serialize(serializer)
+    }
+
+    /// Load a theme with the given deserializer and apply it to this context. Returns the
+    /// deserializer's error without changing the style if the theme contains unknown keys or
+    /// malformed values.
+    ///
+    /// ```no_run
+    /// # let mut plot_context = implot::Context::create();
+    /// let theme = r#"{ "StyleVars": { "LineWeight": 2.0 }, "Colormap": "Viridis" }"#;
+    /// plot_context
+    ///     .load_theme(&mut serde_json::Deserializer::from_str(theme))
+    ///     .expect("Could not load the theme");
+    /// ```
+    pub fn load_theme<'de, D: Deserializer<'de>>(
+        &mut self,
+        deserializer: D,
+    ) -> Result<(), D::Error> {
+        let theme = PlotTheme::deserialize(deserializer)?;
+        self.apply_theme(&theme);
+        Ok(())
+    }
+}